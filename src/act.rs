@@ -0,0 +1,30 @@
+use crate::silksong_memory::Env;
+
+/// The act of the save file, from the act2Started and blackThreadWorld flags
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Act {
+    Act1,
+    Act2,
+    Act3,
+}
+
+impl Act {
+    pub fn name(self) -> &'static str {
+        match self {
+            Act::Act1 => "Act 1",
+            Act::Act2 => "Act 2",
+            Act::Act3 => "Act 3",
+        }
+    }
+}
+
+pub fn get_act(e: &Env) -> Result<Act, asr::Error> {
+    let Env { mem, pd, .. } = e;
+    if mem.deref(&pd.black_thread_world)? {
+        Ok(Act::Act3)
+    } else if mem.deref(&pd.act2_started)? {
+        Ok(Act::Act2)
+    } else {
+        Ok(Act::Act1)
+    }
+}
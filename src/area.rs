@@ -0,0 +1,178 @@
+use alloc::{borrow::ToOwned, collections::BTreeSet, string::String, vec::Vec};
+
+use crate::{
+    scene_catalog::scene_map_zone,
+    silksong_memory::{get_map_zone, is_menu, Env},
+};
+
+/// The game's map zones, named like `GlobalEnums.MapZone`, with their area names,
+/// the same names that the scene catalog's scenes get
+static MAP_ZONES: [(&str, &str); 31] = [
+    ("ABYSS", "The Abyss"),
+    ("AQUEDUCT", "Putrified Ducts"),
+    ("ARBORIUM", "Memorium"),
+    ("BELLTOWN", "Bellhart"),
+    ("BONETOWN", "Bone Bottom"),
+    ("CITY_OF_SONG", "Choral Chambers"),
+    ("CLOVER", "Verdania"),
+    ("COG_CORE", "Cogwork Core"),
+    ("CORAL_CAVERNS", "Sands of Karak"),
+    ("CRADLE", "The Cradle"),
+    ("CRAWLSPACE", "Wormways"),
+    ("DOCKS", "Deep Docks"),
+    ("DUSTPENS", "Sinner's Road"),
+    ("DUST_MAZE", "The Mist"),
+    ("GREYMOOR", "Greymoor"),
+    ("HANG", "High Halls"),
+    ("HUNTERS_NEST", "Hunter's March"),
+    ("JUDGE_STEPS", "Blasted Steps"),
+    ("LIBRARY", "Whispering Vaults"),
+    ("MOSSTOWN", "Mosshome"),
+    ("MOSS_CAVE", "Moss Grotto"),
+    ("PATH_OF_BONE", "Marrow"),
+    ("PEAK", "Mount Fay"),
+    ("SHELLWOOD_THICKET", "Shellwood"),
+    ("SONG_ENCLAVE", "Songclave"),
+    ("SWAMP", "Bilewater"),
+    ("THE_SLAB", "The Slab"),
+    ("UNDERSTORE", "Underworks"),
+    ("WARD", "Whiteward"),
+    ("WILDS", "Far Fields"),
+    ("WISP", "Wisp Thicket"),
+];
+
+/// The area name of a map zone such as `THE_SLAB`, if it is known
+pub fn map_zone_area(zone: &str) -> Option<&'static str> {
+    MAP_ZONES
+        .iter()
+        .find(|(z, _)| *z == zone)
+        .map(|(_, area)| *area)
+}
+
+/// The area of a scene, from the game's current map zone,
+/// or else from the scene's map zone in the scene catalog
+/// when the current map zone can't be read or isn't known
+pub fn area_name(scene: &str, map_zone: Option<&str>) -> Option<String> {
+    map_zone
+        .and_then(map_zone_area)
+        .or_else(|| map_zone_area(scene_map_zone(scene)?))
+        .map(ToOwned::to_owned)
+}
+
+/// Whether an area name matches a name from the settings, ignoring case
+fn same_area(area: &str, name: &str) -> bool {
+    area.eq_ignore_ascii_case(name.trim())
+}
+
+/// The current area, and the areas and rooms entered so far during the run
+pub struct AreaTracker {
+    current: Option<String>,
+    current_scene: Option<String>,
+    visited: Vec<String>,
+    changed: bool,
+    entered_new: bool,
+    visited_scenes: BTreeSet<String>,
+    entered_new_scene: bool,
+}
+
+impl AreaTracker {
+    pub fn new() -> AreaTracker {
+        AreaTracker {
+            current: None,
+            current_scene: None,
+            visited: Vec::new(),
+            changed: false,
+            entered_new: false,
+            visited_scenes: BTreeSet::new(),
+            entered_new_scene: false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.current = None;
+        self.current_scene = None;
+        self.visited.clear();
+        self.visited_scenes.clear();
+    }
+
+    /// Forgets the areas and rooms entered before the run started,
+    /// except for the ones the run starts in
+    pub fn start_run(&mut self) {
+        self.visited.clear();
+        self.visited.extend(self.current.clone());
+        self.visited_scenes.clear();
+        self.visited_scenes.extend(self.current_scene.clone());
+    }
+
+    /// Updates the area on a transition into `scene`
+    pub fn update(&mut self, trans_now: bool, scene: &str, e: &Env) {
+        self.changed = false;
+        self.entered_new = false;
+        self.entered_new_scene = false;
+        if !trans_now || is_menu(scene) {
+            return;
+        }
+        if !self.visited_scenes.contains(scene) {
+            self.entered_new_scene = true;
+            self.visited_scenes.insert(scene.to_owned());
+        }
+        self.current_scene = Some(scene.to_owned());
+        let Some(area) = area_name(scene, get_map_zone(Some(e)).as_deref()) else {
+            return;
+        };
+        if self.current.as_ref() == Some(&area) {
+            return;
+        }
+        self.changed = true;
+        if !self.visited.contains(&area) {
+            self.entered_new = true;
+            self.visited.push(area.clone());
+        }
+        self.current = Some(area);
+    }
+
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// Whether the last transition went into a different area
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+
+    /// Whether the last transition went into an area for the first time this run
+    pub fn entered_new(&self) -> bool {
+        self.entered_new
+    }
+
+    /// Whether the last transition went into the named area,
+    /// either from another area, or for the first time this run with `first_time`
+    pub fn entered(&self, name: &str, first_time: bool) -> bool {
+        let entered = if first_time {
+            self.entered_new
+        } else {
+            self.changed
+        };
+        entered
+            && self
+                .current
+                .as_deref()
+                .is_some_and(|area| same_area(area, name))
+    }
+
+    /// Whether the last transition went into a room for the first time this run
+    pub fn entered_new_scene(&self) -> bool {
+        self.entered_new_scene
+    }
+
+    /// How many different rooms have been entered this run
+    pub fn rooms_visited(&self) -> usize {
+        self.visited_scenes.len()
+    }
+}
+
+impl Default for AreaTracker {
+    fn default() -> Self {
+        AreaTracker::new()
+    }
+}
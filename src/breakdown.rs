@@ -0,0 +1,95 @@
+use asr::time::Duration;
+
+use crate::timer::TimeVariable;
+
+pub const BREAKDOWN_LEN: usize = 4;
+
+/// The variables for the run totals of each kind of time in the breakdown:
+/// loads, the pause menu, the inventory, and cutscenes
+pub static RUN_VARIABLES: [&str; BREAKDOWN_LEN] =
+    ["load time", "pause time", "inventory time", "cutscene time"];
+
+/// The variables for the current segment, in the same order as `RUN_VARIABLES`
+pub static SEGMENT_VARIABLES: [&str; BREAKDOWN_LEN] = [
+    "segment load time",
+    "segment pause time",
+    "segment inventory time",
+    "segment cutscene time",
+];
+
+/// Real time spent in loads, the pause menu, the inventory, and cutscenes,
+/// for the current segment and for the run
+pub struct Breakdown {
+    split_index: Option<u64>,
+    segment: [Duration; BREAKDOWN_LEN],
+    run: [Duration; BREAKDOWN_LEN],
+    segment_variables: [TimeVariable; BREAKDOWN_LEN],
+    run_variables: [TimeVariable; BREAKDOWN_LEN],
+}
+
+impl Breakdown {
+    pub fn new() -> Breakdown {
+        Breakdown {
+            split_index: None,
+            segment: [Duration::ZERO; BREAKDOWN_LEN],
+            run: [Duration::ZERO; BREAKDOWN_LEN],
+            segment_variables: SEGMENT_VARIABLES.map(TimeVariable::new),
+            run_variables: RUN_VARIABLES.map(TimeVariable::new),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.split_index = None;
+        self.segment = [Duration::ZERO; BREAKDOWN_LEN];
+        self.run = [Duration::ZERO; BREAKDOWN_LEN];
+        for v in self
+            .segment_variables
+            .iter_mut()
+            .chain(self.run_variables.iter_mut())
+        {
+            v.clear();
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.run.iter().all(|t| t.is_zero())
+    }
+
+    /// The run totals in seconds, in the order of `RUN_VARIABLES`
+    pub fn run_seconds(&self) -> [f64; BREAKDOWN_LEN] {
+        self.run.map(|t| t.as_seconds_f64())
+    }
+
+    /// Adds the real time since the last tick to each kind of time that is active,
+    /// starting a new segment when the split index changes
+    pub fn tick(&mut self, split_index: Option<u64>, active: [bool; BREAKDOWN_LEN], dt: Duration) {
+        if split_index != self.split_index {
+            self.split_index = split_index;
+            self.segment = [Duration::ZERO; BREAKDOWN_LEN];
+            for v in self.segment_variables.iter_mut() {
+                v.set(Duration::ZERO);
+            }
+        }
+        for (i, &a) in active.iter().enumerate() {
+            if !a {
+                continue;
+            }
+            self.segment[i] += dt;
+            self.run[i] += dt;
+            self.segment_variables[i].set(self.segment[i]);
+            self.run_variables[i].set(self.run[i]);
+        }
+    }
+}
+
+impl Default for Breakdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn set_breakdown_variables(value: &str) {
+    for name in RUN_VARIABLES.iter().chain(SEGMENT_VARIABLES.iter()) {
+        asr::timer::set_variable(name, value);
+    }
+}
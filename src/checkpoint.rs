@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+
+use crate::{settings_get_list, settings_list};
+
+/// The in-progress counters of a run,
+/// saved into the settings map so that they survive the auto splitter being reloaded mid-run
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Checkpoint {
+    /// None: NotRunning
+    pub split_index: Option<u64>,
+    pub segments_splitted: Vec<bool>,
+    pub hits: i64,
+    pub segment_hits: Vec<i64>,
+    pub cumulative_hits: Vec<i64>,
+    pub deaths: i64,
+    pub segment_deaths: Vec<i64>,
+    pub cumulative_deaths: Vec<i64>,
+    pub play_time: Option<f64>,
+}
+
+impl Checkpoint {
+    /// Produces the checkpoint fitted to the split index the timer is at now,
+    /// or None if it was saved at a different split index, or between runs
+    pub fn restore_at(mut self, index: u64) -> Option<Checkpoint> {
+        if self.split_index != Some(index) {
+            return None;
+        }
+        let i = index as usize;
+        self.segments_splitted.resize(i, false);
+        self.segment_hits.resize(i + 1, 0);
+        self.segment_deaths.resize(i + 1, 0);
+        Some(self)
+    }
+
+    pub fn store(&self) {
+        let split_index = self.split_index.map_or(-1, |i| i as i64);
+        let segments_splitted = settings_list(&self.segments_splitted);
+        let segment_hits = settings_list(&self.segment_hits);
+        let cumulative_hits = settings_list(&self.cumulative_hits);
+        let segment_deaths = settings_list(&self.segment_deaths);
+        let cumulative_deaths = settings_list(&self.cumulative_deaths);
+        loop {
+            let old = asr::settings::Map::load();
+            let new = old.clone();
+            new.insert("checkpoint_split_index", split_index);
+            new.insert("checkpoint_segments_splitted", &segments_splitted);
+            new.insert("checkpoint_hits", self.hits);
+            new.insert("checkpoint_segment_hits", &segment_hits);
+            new.insert("checkpoint_cumulative_hits", &cumulative_hits);
+            new.insert("checkpoint_deaths", self.deaths);
+            new.insert("checkpoint_segment_deaths", &segment_deaths);
+            new.insert("checkpoint_cumulative_deaths", &cumulative_deaths);
+            new.insert("checkpoint_play_time", self.play_time.unwrap_or_default());
+            if new.store_if_unchanged(&old) {
+                return;
+            }
+        }
+    }
+
+    pub fn load() -> Checkpoint {
+        let m = asr::settings::Map::load();
+        Checkpoint {
+            split_index: m
+                .get("checkpoint_split_index")
+                .and_then(|v| v.get_i64())
+                .and_then(|i| u64::try_from(i).ok()),
+            segments_splitted: settings_get_list(&m, "checkpoint_segments_splitted", |v| {
+                v.get_bool()
+            }),
+            hits: m
+                .get("checkpoint_hits")
+                .and_then(|v| v.get_i64())
+                .unwrap_or_default(),
+            segment_hits: settings_get_list(&m, "checkpoint_segment_hits", |v| v.get_i64()),
+            cumulative_hits: settings_get_list(&m, "checkpoint_cumulative_hits", |v| v.get_i64()),
+            deaths: m
+                .get("checkpoint_deaths")
+                .and_then(|v| v.get_i64())
+                .unwrap_or_default(),
+            segment_deaths: settings_get_list(&m, "checkpoint_segment_deaths", |v| v.get_i64()),
+            cumulative_deaths: settings_get_list(&m, "checkpoint_cumulative_deaths", |v| {
+                v.get_i64()
+            }),
+            play_time: m.get("checkpoint_play_time").and_then(|v| v.get_f64()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn mid_run() -> Checkpoint {
+        Checkpoint {
+            split_index: Some(2),
+            segments_splitted: vec![true, false],
+            hits: 7,
+            segment_hits: vec![3, 0, 4],
+            cumulative_hits: vec![3],
+            deaths: 2,
+            segment_deaths: vec![1, 0, 1],
+            cumulative_deaths: vec![1],
+            play_time: Some(123.5),
+        }
+    }
+
+    #[test]
+    fn restores_after_reload_at_the_same_split() {
+        assert_eq!(mid_run().restore_at(2), Some(mid_run()));
+    }
+
+    #[test]
+    fn ignores_a_checkpoint_from_another_split() {
+        assert_eq!(mid_run().restore_at(1), None);
+        assert_eq!(mid_run().restore_at(3), None);
+    }
+
+    #[test]
+    fn fits_short_lists_to_the_split_index() {
+        let checkpoint = Checkpoint {
+            segment_hits: vec![3],
+            segments_splitted: vec![true],
+            ..mid_run()
+        };
+        let restored = checkpoint.restore_at(2).unwrap();
+        assert_eq!(restored.segments_splitted, vec![true, false]);
+        assert_eq!(restored.segment_hits, vec![3, 0, 0]);
+        assert_eq!(restored.segment_deaths.len(), 3);
+    }
+
+    #[test]
+    fn clears_on_reset() {
+        // a reset stores the cleared counters, which are between runs
+        let cleared = Checkpoint::default();
+        assert_eq!(cleared.split_index, None);
+        assert_eq!(cleared.clone().restore_at(0), None);
+        assert_eq!(cleared.restore_at(2), None);
+    }
+}
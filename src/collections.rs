@@ -0,0 +1,176 @@
+use alloc::{format, string::String};
+use asr::game_engine::unity::mono::UnityPointer;
+
+use crate::silksong_memory::{Env, PlayerDataPointers};
+
+type Flag = fn(&PlayerDataPointers) -> &UnityPointer<3>;
+
+static FLEAS: [Flag; 30] = [
+    |pd| &pd.savedflea_ant_03,
+    |pd| &pd.savedflea_belltown_04,
+    |pd| &pd.savedflea_bone_06,
+    |pd| &pd.savedflea_bone_east_05,
+    |pd| &pd.savedflea_bone_east_10_church,
+    |pd| &pd.savedflea_bone_east_17b,
+    |pd| &pd.savedflea_coral_24,
+    |pd| &pd.savedflea_coral_35,
+    |pd| &pd.savedflea_crawl_06,
+    |pd| &pd.savedflea_dock_03d,
+    |pd| &pd.savedflea_dock_16,
+    |pd| &pd.savedflea_dust_09,
+    |pd| &pd.savedflea_dust_12,
+    |pd| &pd.savedflea_greymoor_06,
+    |pd| &pd.savedflea_greymoor_15b,
+    |pd| &pd.savedflea_library_01,
+    |pd| &pd.savedflea_library_09,
+    |pd| &pd.savedflea_peak_05c,
+    |pd| &pd.savedflea_shadow_10,
+    |pd| &pd.savedflea_shadow_28,
+    |pd| &pd.savedflea_shellwood_03,
+    |pd| &pd.savedflea_slab_06,
+    |pd| &pd.savedflea_slab_cell,
+    |pd| &pd.savedflea_song_11,
+    |pd| &pd.savedflea_song_14,
+    |pd| &pd.savedflea_under_21,
+    |pd| &pd.savedflea_under_23,
+    |pd| &pd.tamed_giant_flea,
+    |pd| &pd.met_troupe_hunter_wild,
+    |pd| &pd.caravan_lech_saved,
+];
+
+/// Memory orbs that are a single bool each
+static ORB_SINGLES: [Flag; 7] = [
+    |pd| &pd.orbs_02c,
+    |pd| &pd.orbs_03,
+    |pd| &pd.orbs_06,
+    |pd| &pd.orbs_11,
+    |pd| &pd.orbs_16_b,
+    |pd| &pd.orbs_16_c,
+    |pd| &pd.orbs_21,
+];
+
+/// Memory orbs that are bits in a C# ulong, an unsigned 64-bit int
+static ORB_MULTIS: [Flag; 6] = [
+    |pd| &pd.orbs_18_a,
+    |pd| &pd.orbs_18_b,
+    |pd| &pd.orbs_18_c,
+    |pd| &pd.orbs_18_d,
+    |pd| &pd.orbs_18_e,
+    |pd| &pd.orbs_19,
+];
+
+static MAPS: [Flag; 14] = [
+    |pd| &pd.has_moss_grotto_map,
+    |pd| &pd.has_boneforest_map,
+    |pd| &pd.has_docks_map,
+    |pd| &pd.has_wilds_map,
+    |pd| &pd.has_crawl_map,
+    |pd| &pd.has_greymoor_map,
+    |pd| &pd.has_bellhart_map,
+    |pd| &pd.has_shellwood_map,
+    |pd| &pd.has_hunters_nest_map,
+    |pd| &pd.has_judge_steps_map,
+    |pd| &pd.has_dustpens_map,
+    |pd| &pd.has_peak_map,
+    |pd| &pd.has_swamp_map,
+    |pd| &pd.has_coral_map,
+];
+
+static BELLWAYS: [Flag; 10] = [
+    |pd| &pd.unlocked_aqueduct_station,
+    |pd| &pd.unlocked_belltown_station,
+    |pd| &pd.unlocked_boneforest_east_station,
+    |pd| &pd.unlocked_city_station,
+    |pd| &pd.unlocked_coral_tower_station,
+    |pd| &pd.unlocked_docks_station,
+    |pd| &pd.unlocked_greymoor_station,
+    |pd| &pd.unlocked_peak_station,
+    |pd| &pd.unlocked_shadow_station,
+    |pd| &pd.unlocked_shellwood_station,
+];
+
+static VENTRICAS: [Flag; 6] = [
+    |pd| &pd.unlocked_song_tube,
+    |pd| &pd.unlocked_under_tube,
+    |pd| &pd.unlocked_city_bellway_tube,
+    |pd| &pd.unlocked_hang_tube,
+    |pd| &pd.unlocked_enclave_tube,
+    |pd| &pd.unlocked_arborium_tube,
+];
+
+/// A set of PlayerData flags that is counted as a whole
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Collection {
+    Fleas,
+    MemoryOrbs,
+    Maps,
+    Bellways,
+    Ventricas,
+}
+
+impl Collection {
+    pub const ALL: [Collection; 5] = [
+        Collection::Fleas,
+        Collection::MemoryOrbs,
+        Collection::Maps,
+        Collection::Bellways,
+        Collection::Ventricas,
+    ];
+
+    /// The name of the variable, such as `fleas`
+    pub fn name(self) -> &'static str {
+        match self {
+            Collection::Fleas => "fleas",
+            Collection::MemoryOrbs => "memory orbs",
+            Collection::Maps => "maps",
+            Collection::Bellways => "bellways",
+            Collection::Ventricas => "ventricas",
+        }
+    }
+
+    pub fn total(self) -> i32 {
+        match self {
+            Collection::Fleas => FLEAS.len() as i32,
+            // the number of Verdania Orbs required
+            Collection::MemoryOrbs => 12,
+            Collection::Maps => MAPS.len() as i32,
+            Collection::Bellways => BELLWAYS.len() as i32,
+            Collection::Ventricas => VENTRICAS.len() as i32,
+        }
+    }
+
+    pub fn count(self, e: &Env) -> Result<i32, asr::Error> {
+        match self {
+            Collection::Fleas => count_flags(&FLEAS, e),
+            Collection::MemoryOrbs => {
+                let Env { mem, pd, .. } = e;
+                let mut multis = 0;
+                for p in ORB_MULTIS.iter() {
+                    let orbs: u64 = mem.deref(p(pd))?;
+                    multis += orbs.count_ones() as i32;
+                }
+                Ok(count_flags(&ORB_SINGLES, e)? + multis)
+            }
+            Collection::Maps => count_flags(&MAPS, e),
+            Collection::Bellways => count_flags(&BELLWAYS, e),
+            Collection::Ventricas => count_flags(&VENTRICAS, e),
+        }
+    }
+
+    /// The count out of the total, such as `12/30`
+    pub fn count_string(self, e: &Env) -> Option<String> {
+        let count = self.count(e).ok()?;
+        Some(format!("{}/{}", count, self.total()))
+    }
+}
+
+fn count_flags(flags: &[Flag], e: &Env) -> Result<i32, asr::Error> {
+    let Env { mem, pd, .. } = e;
+    let mut count = 0;
+    for p in flags.iter() {
+        if mem.deref::<bool, _>(p(pd))? {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
@@ -0,0 +1,403 @@
+use alloc::{format, string::String, vec, vec::Vec};
+use asr::game_engine::unity::mono::UnityPointer;
+use utf16_lit::utf16;
+
+use crate::silksong_memory::{Env, PlayerDataPointers, ToolData, TOOLS_LIST};
+
+const START_MASKS: i32 = 5;
+const SHARDS_PER_MASK: i32 = 4;
+const TOTAL_MASK_SHARDS: i32 = 20;
+const START_SPOOLS: i32 = 9;
+const FRAGMENTS_PER_SPOOL: i32 = 2;
+const TOTAL_SPOOL_FRAGMENTS: i32 = 18;
+const TOTAL_UPGRADES: i32 = 4;
+
+type Flag = (
+    &'static str,
+    &'static str,
+    fn(&PlayerDataPointers) -> &UnityPointer<3>,
+);
+
+/// Each flag counts 1%, listed with its region from `splits.rs`
+static FLAGS: [Flag; 22] = [
+    ("Silkspear", "MossLands", |pd| &pd.has_needle_throw),
+    ("Swift Step", "DeepDocks", |pd| &pd.has_dash),
+    ("Sharpdart", "Wormways", |pd| &pd.has_silk_charge),
+    ("Drifter's Cloak", "FarFields", |pd| &pd.has_brolly),
+    ("Thread Storm", "Greymoor", |pd| &pd.has_thread_sphere),
+    ("Cling Grip", "Shellwood", |pd| &pd.has_wall_jump),
+    ("Needle Strike", "BlastedSteps", |pd| &pd.has_charge_slash),
+    ("Cross Stitch", "Bilewater", |pd| &pd.has_parry),
+    ("Rune Rage", "TheSlab", |pd| &pd.has_silk_bomb),
+    ("Faydown Cloak", "MountFay", |pd| &pd.has_double_jump),
+    ("Clawline", "Underworks", |pd| &pd.has_harpoon_dash),
+    ("Pale Nails", "TheCradle", |pd| &pd.has_silk_boss_needle),
+    ("Reaper Crest", "Crests", |pd| &pd.completed_memory_reaper),
+    ("Wanderer Crest", "Crests", |pd| {
+        &pd.completed_memory_wanderer
+    }),
+    ("Beast Crest", "Crests", |pd| &pd.completed_memory_beast),
+    ("Architect Crest", "Crests", |pd| {
+        &pd.completed_memory_toolmaster
+    }),
+    ("Witch Crest", "Crests", |pd| {
+        &pd.belltown_doctor_cured_curse
+    }),
+    ("Shaman Crest", "Crests", |pd| &pd.completed_memory_shaman),
+    ("Vesticrest Yellow Slot", "Crests", |pd| {
+        &pd.unlocked_extra_yellow_slot
+    }),
+    ("Vesticrest Blue Slot", "Crests", |pd| {
+        &pd.unlocked_extra_blue_slot
+    }),
+    ("Sylphsong", "Crests", |pd| &pd.has_bound_crest_upgrader),
+    ("Silk Soar", "MiscTE", |pd| &pd.has_super_jump),
+];
+
+type Tool = (&'static str, &'static str, &'static [&'static [u16]]);
+
+/// Each tool counts 1%, listed with the region from `splits.rs` it is found in,
+/// and any of its ids unlocks it,
+/// so an upgraded tool counts once together with its base tool
+static TOOLS: [Tool; 52] = [
+    ("Straight Pin", "MossLands", &[&utf16!("Straight Pin")]),
+    ("Threefold Pin", "Marrow", &[&utf16!("Tri Pin")]),
+    ("Sting Shard", "Bellhart", &[&utf16!("Sting Shard")]),
+    ("Tacks", "MossLands", &[&utf16!("Tack")]),
+    ("Longpin", "FarFields", &[&utf16!("Harpoon")]),
+    (
+        "Curveclaw",
+        "HuntersMarch",
+        &[&utf16!("Curve Claws"), &utf16!("Curve Claws Upgraded")],
+    ),
+    ("Throwing Ring", "Greymoor", &[&utf16!("Shakra Ring")]),
+    ("Pimpillo", "Greymoor", &[&utf16!("Pimpilo")]),
+    ("Conchcutter", "SandsOfKarak", &[&utf16!("Conch Drill")]),
+    (
+        "Silkshot",
+        "Underworks",
+        &[
+            &utf16!("WebShot Forge"),
+            &utf16!("WebShot Architect"),
+            &utf16!("WebShot Weaver"),
+        ],
+    ),
+    ("Delver's Drill", "Marrow", &[&utf16!("Screw Attack")]),
+    ("Cogwork Wheel", "Underworks", &[&utf16!("Cogwork Saw")]),
+    ("Cogfly", "CogworkCore", &[&utf16!("Cogwork Flier")]),
+    ("Rosary Cannon", "HighHalls", &[&utf16!("Rosary Cannon")]),
+    ("Voltvessels", "SandsOfKarak", &[&utf16!("Lightning Rod")]),
+    ("Flintslate", "DeepDocks", &[&utf16!("Flintstone")]),
+    ("Snare Setter", "FarFields", &[&utf16!("Silk Snare")]),
+    ("Flea Brew", "FleaSpecific", &[&utf16!("Flea Brew")]),
+    (
+        "Plasmium Phial",
+        "Bilewater",
+        &[&utf16!("Lifeblood Syringe")],
+    ),
+    ("Needle Phial", "Shellwood", &[&utf16!("Extractor")]),
+    (
+        "Druid's Eye",
+        "MossLands",
+        &[&utf16!("Mosscreep Tool 1"), &utf16!("Mosscreep Tool 2")],
+    ),
+    ("Magma Bell", "DeepDocks", &[&utf16!("Lava Charm")]),
+    ("Warding Bell", "Bellhart", &[&utf16!("Bell Bind")]),
+    ("Pollip Pouch", "SinnersRoad", &[&utf16!("Poison Pouch")]),
+    ("Fractured Mask", "Greymoor", &[&utf16!("Fractured Mask")]),
+    ("Multibinder", "Shellwood", &[&utf16!("Multibind")]),
+    ("Weavelight", "Greymoor", &[&utf16!("White Ring")]),
+    ("Sawtooth Circlet", "FarFields", &[&utf16!("Brolly Spike")]),
+    ("Injector Band", "Wormways", &[&utf16!("Quickbind")]),
+    ("Spool Extender", "Bellhart", &[&utf16!("Spool Extender")]),
+    ("Reserve Bind", "Shellwood", &[&utf16!("Reserve Bind")]),
+    (
+        "Claw Mirror",
+        "ChoralChambers",
+        &[&utf16!("Dazzle Bind"), &utf16!("Dazzle Bind Upgraded")],
+    ),
+    ("Memory Crystal", "Memorium", &[&utf16!("Revenge Crystal")]),
+    ("Snitch Pick", "BlastedSteps", &[&utf16!("Thief Claw")]),
+    ("Volt Filament", "SandsOfKarak", &[&utf16!("Zap Imbuement")]),
+    ("Quick Sling", "ChoralChambers", &[&utf16!("Quick Sling")]),
+    (
+        "Wreath of Purity",
+        "SinnersRoad",
+        &[&utf16!("Maggot Charm")],
+    ),
+    ("Longclaw", "Greymoor", &[&utf16!("Longneedle")]),
+    (
+        "Wispfire Lantern",
+        "WispThicket",
+        &[&utf16!("Wisp Lantern")],
+    ),
+    ("Pin Badge", "BlastedSteps", &[&utf16!("Pinstress Tool")]),
+    ("Compass", "MossLands", &[&utf16!("Compass")]),
+    ("Shard Pendant", "Marrow", &[&utf16!("Bone Necklace")]),
+    ("Magnetite Brooch", "Bellhart", &[&utf16!("Rosary Magnet")]),
+    ("Weighted Belt", "DeepDocks", &[&utf16!("Weighted Anklet")]),
+    ("Barbed Bracelet", "Bilewater", &[&utf16!("Barbed Wire")]),
+    (
+        "Dead Bug's Purse",
+        "Greymoor",
+        &[&utf16!("Dead Mans Purse")],
+    ),
+    ("Shell Satchel", "Shellwood", &[&utf16!("Shell Satchel")]),
+    (
+        "Magnetite Dice",
+        "ChoralChambers",
+        &[&utf16!("Magnetite Dice")],
+    ),
+    ("Scuttlebrace", "Bilewater", &[&utf16!("Scuttlebrace")]),
+    ("Ascendant's Grip", "BlastedSteps", &[&utf16!("Wallcling")]),
+    ("Spider Strings", "Bellhart", &[&utf16!("Musician Charm")]),
+    ("Silkspeed Anklets", "FarFields", &[&utf16!("Sprintmaster")]),
+    ("Thief's Mark", "SinnersRoad", &[&utf16!("Thief Charm")]),
+];
+
+/// One line of the completion checklist
+pub struct CompletionItem {
+    pub name: &'static str,
+    /// The region from `splits.rs` that the item is listed under
+    pub region: &'static str,
+    pub have: i32,
+    pub total: i32,
+    /// How many of this item count for 1%
+    pub per_percent: i32,
+    /// The names of the parts that are still missing, for items made of named parts
+    pub missing: Vec<&'static str>,
+}
+
+impl CompletionItem {
+    pub fn percent(&self) -> i32 {
+        self.have.clamp(0, self.total) / self.per_percent
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.total <= self.have
+    }
+}
+
+/// Completion percent recomputed from the PlayerData it is made of,
+/// instead of waiting for the game to recalculate `completionPercentage`
+pub struct Completion {
+    tools_version: Option<i32>,
+    tools: Vec<bool>,
+    items: Vec<CompletionItem>,
+}
+
+impl Completion {
+    pub fn new() -> Completion {
+        Completion {
+            tools_version: None,
+            tools: vec![false; TOOLS.len()],
+            items: Vec::new(),
+        }
+    }
+
+    pub fn percent(&self) -> i32 {
+        self.items.iter().map(CompletionItem::percent).sum()
+    }
+
+    pub fn update(&mut self, e: &Env) {
+        let Env { mem, pd, .. } = e;
+        self.update_tools(e);
+        let masks: i32 = mem.deref(&pd.max_health_base).unwrap_or(START_MASKS);
+        let heart_pieces: i32 = mem.deref(&pd.heart_pieces).unwrap_or_default();
+        let spools: i32 = mem.deref(&pd.silk_max).unwrap_or(START_SPOOLS);
+        let spool_parts: i32 = mem.deref(&pd.silk_spool_parts).unwrap_or_default();
+        let upgrades = |p: &UnityPointer<3>| mem.deref::<i32, _>(p).unwrap_or_default();
+        self.items.clear();
+        self.items.push(CompletionItem {
+            name: "Needle Upgrades",
+            region: "NeedleUpgrade",
+            have: upgrades(&pd.nail_upgrades),
+            total: TOTAL_UPGRADES,
+            per_percent: 1,
+            missing: Vec::new(),
+        });
+        self.items.push(CompletionItem {
+            name: "Mask Shards",
+            region: "MaskShards",
+            have: (masks - START_MASKS) * SHARDS_PER_MASK + heart_pieces,
+            total: TOTAL_MASK_SHARDS,
+            per_percent: SHARDS_PER_MASK,
+            missing: Vec::new(),
+        });
+        self.items.push(CompletionItem {
+            name: "Spool Fragments",
+            region: "SpoolFragments",
+            have: (spools - START_SPOOLS) * FRAGMENTS_PER_SPOOL + spool_parts,
+            total: TOTAL_SPOOL_FRAGMENTS,
+            per_percent: FRAGMENTS_PER_SPOOL,
+            missing: Vec::new(),
+        });
+        self.items.push(CompletionItem {
+            name: "Tool Pouch",
+            region: "ToolPouchLevels",
+            have: upgrades(&pd.tool_pouch_upgrades),
+            total: TOTAL_UPGRADES,
+            per_percent: 1,
+            missing: Vec::new(),
+        });
+        self.items.push(CompletionItem {
+            name: "Crafting Kit",
+            region: "CraftingKitLevels",
+            have: upgrades(&pd.tool_kit_upgrades),
+            total: TOTAL_UPGRADES,
+            per_percent: 1,
+            missing: Vec::new(),
+        });
+        for &(name, region, p) in FLAGS.iter() {
+            let has: bool = mem.deref(p(pd)).unwrap_or_default();
+            self.items.push(CompletionItem {
+                name,
+                region,
+                have: has as i32,
+                total: 1,
+                per_percent: 1,
+                missing: if has { Vec::new() } else { vec![name] },
+            });
+        }
+        for (&(name, region, _), &has) in TOOLS.iter().zip(self.tools.iter()) {
+            self.items.push(CompletionItem {
+                name,
+                region,
+                have: has as i32,
+                total: 1,
+                per_percent: 1,
+                missing: if has { Vec::new() } else { vec![name] },
+            });
+        }
+    }
+
+    /// The remaining items grouped by region,
+    /// such as `Mask Shards 14/20` or `Sinners Road missing: Wreath of Purity, Pollip Pouch`
+    pub fn missing_string(&self) -> String {
+        let mut groups: Vec<String> = Vec::new();
+        let mut region_missing: Vec<(&str, Vec<&str>)> = Vec::new();
+        for item in self.items.iter().filter(|item| !item.is_done()) {
+            if item.missing.is_empty() {
+                groups.push(format!("{} {}/{}", item.name, item.have.max(0), item.total));
+            } else if let Some((_, names)) = region_missing
+                .iter_mut()
+                .find(|(region, _)| *region == item.region)
+            {
+                names.extend(item.missing.iter());
+            } else {
+                region_missing.push((item.region, item.missing.clone()));
+            }
+        }
+        for (region, names) in region_missing {
+            groups.push(format!(
+                "{} missing: {}",
+                region_label(region),
+                names.join(", ")
+            ));
+        }
+        groups.join(" | ")
+    }
+
+    /// Rescans the tools only when their version changes
+    fn update_tools(&mut self, e: &Env) {
+        let Env { mem, pd, .. } = e;
+        let version = TOOLS_LIST.get_version(mem, pd);
+        if version.is_none() || version == self.tools_version {
+            return;
+        }
+        self.tools.fill(false);
+        let tools = &mut self.tools;
+        let scanned = TOOLS_LIST.for_each(mem, pd, |id, tool: ToolData| {
+            if !tool.is_unlocked {
+                return;
+            }
+            if let Some(i) = TOOLS
+                .iter()
+                .position(|(_, _, ids)| ids.iter().any(|t| *t == id))
+            {
+                tools[i] = true;
+            }
+        });
+        self.tools_version = scanned.and(version);
+    }
+}
+
+/// Spaces out a region name from `splits.rs`, so `MossLands` becomes `Moss Lands`
+fn region_label(region: &str) -> String {
+    let mut label = String::new();
+    let mut last_lower = false;
+    for c in region.chars() {
+        if last_lower && c.is_ascii_uppercase() {
+            label.push(' ');
+        }
+        label.push(c);
+        last_lower = c.is_ascii_lowercase();
+    }
+    label
+}
+
+impl Default for Completion {
+    fn default() -> Self {
+        Completion::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn everything_adds_up_to_100_percent() {
+        let max = 3 * TOTAL_UPGRADES
+            + TOTAL_MASK_SHARDS / SHARDS_PER_MASK
+            + TOTAL_SPOOL_FRAGMENTS / FRAGMENTS_PER_SPOOL
+            + FLAGS.len() as i32
+            + TOOLS.len() as i32;
+        assert_eq!(max, 100);
+    }
+
+    fn item(name: &'static str, region: &'static str, have: i32) -> CompletionItem {
+        CompletionItem {
+            name,
+            region,
+            have,
+            total: 1,
+            per_percent: 1,
+            missing: if have < 1 { vec![name] } else { Vec::new() },
+        }
+    }
+
+    #[test]
+    fn groups_missing_abilities_and_tools_by_region() {
+        let mut completion = Completion::new();
+        completion.items = vec![
+            CompletionItem {
+                name: "Mask Shards",
+                region: "MaskShards",
+                have: 14,
+                total: TOTAL_MASK_SHARDS,
+                per_percent: SHARDS_PER_MASK,
+                missing: Vec::new(),
+            },
+            item("Thread Storm", "Greymoor", 0),
+            item("Wreath of Purity", "SinnersRoad", 0),
+            item("Silkspear", "MossLands", 1),
+            item("Pimpillo", "Greymoor", 0),
+        ];
+        assert_eq!(
+            completion.missing_string(),
+            "Mask Shards 14/20 | Greymoor missing: Thread Storm, Pimpillo | Sinners Road missing: Wreath of Purity"
+        );
+    }
+
+    #[test]
+    fn each_tool_id_counts_once() {
+        let ids: Vec<&[u16]> = TOOLS
+            .iter()
+            .flat_map(|(_, _, ids)| ids.iter().copied())
+            .collect();
+        for (i, id) in ids.iter().enumerate() {
+            assert!(!ids[i + 1..].contains(id));
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use alloc::string::String;
+
+use crate::silksong_memory::{get_current_crest, Env};
+
+/// Crest ids and the names they are shown with
+pub static CRESTS: [(&str, &str); 10] = [
+    ("Hunter", "Hunter Crest"),
+    ("Hunter_v2", "Hunter Crest Evo 1"),
+    ("Hunter_v3", "Hunter Crest Evo 2"),
+    ("Reaper", "Reaper Crest"),
+    ("Wanderer", "Wanderer Crest"),
+    ("Warrior", "Beast Crest"),
+    ("Witch", "Witch Crest"),
+    ("Toolmaster", "Architect Crest"),
+    ("Spell", "Shaman Crest"),
+    ("Cursed", "Cursed Crest"),
+];
+
+/// The name of a crest id, or the id itself if it isn't known
+pub fn crest_name(id: &str) -> &str {
+    CRESTS
+        .iter()
+        .find(|(crest, _)| *crest == id)
+        .map_or(id, |(_, name)| name)
+}
+
+/// The currently equipped crest, and how many times it has been swapped during the run
+pub struct CrestTracker {
+    current: Option<String>,
+    changed: bool,
+    swaps: i32,
+}
+
+impl CrestTracker {
+    pub fn new() -> CrestTracker {
+        CrestTracker {
+            current: None,
+            changed: false,
+            swaps: 0,
+        }
+    }
+
+    pub fn clear_swaps(&mut self) {
+        self.swaps = 0;
+    }
+
+    pub fn update(&mut self, e: Option<&Env>) {
+        self.changed = false;
+        // forget the crest in menus, so loading another save isn't a swap
+        let Some(crest) = get_current_crest(e) else {
+            self.current = None;
+            return;
+        };
+        if self.current.as_ref().is_some_and(|c| *c != crest) {
+            self.changed = true;
+            self.swaps += 1;
+        }
+        self.current = Some(crest);
+    }
+
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    pub fn changed_to(&self, id: &str) -> bool {
+        self.changed && self.current() == Some(id)
+    }
+
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+
+    pub fn swaps(&self) -> i32 {
+        self.swaps
+    }
+}
+
+impl Default for CrestTracker {
+    fn default() -> Self {
+        CrestTracker::new()
+    }
+}
@@ -0,0 +1,64 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::silksong_memory::Env;
+
+/// Rosaries spent in each scene during the run,
+/// for the scenes that the spending splits look for
+pub struct Spending {
+    spent: Vec<(String, i32)>,
+}
+
+impl Spending {
+    pub fn new() -> Spending {
+        Spending { spent: Vec::new() }
+    }
+
+    pub fn clear(&mut self) {
+        self.spent.clear();
+    }
+
+    /// Adds to the spending in the scene
+    pub fn add(&mut self, scene: &str, amount: i32) {
+        if let Some((_, spent)) = self.spent.iter_mut().find(|(s, _)| s == scene) {
+            *spent += amount;
+        } else {
+            self.spent.push((scene.to_string(), amount));
+        }
+    }
+
+    pub fn get(&self, scene: &str) -> i32 {
+        self.spent
+            .iter()
+            .find(|(s, _)| s == scene)
+            .map_or(0, |(_, spent)| *spent)
+    }
+}
+
+impl Default for Spending {
+    fn default() -> Self {
+        Spending::new()
+    }
+}
+
+/// Rosaries held, including those still being counted up,
+/// plus those left in the cocoon after death, such as `1234 + 56 in cocoon`
+pub fn rosaries_string(e: &Env) -> Option<String> {
+    let Env { mem, pd, .. } = e;
+    let geo: i32 = mem.deref(&pd.geo).ok()?;
+    let temp: i32 = mem.deref(&pd.temp_geo_store).unwrap_or_default();
+    let cocoon: i32 = mem.deref(&pd.hero_corpse_money_pool).unwrap_or_default();
+    if 0 < cocoon {
+        Some(format!("{} + {} in cocoon", geo + temp, cocoon))
+    } else {
+        Some(format!("{}", geo + temp))
+    }
+}
+
+pub fn shell_shards_string(e: &Env) -> Option<String> {
+    let shards: i32 = e.mem.deref(&e.pd.shell_shards).ok()?;
+    Some(format!("{}", shards))
+}
@@ -0,0 +1,67 @@
+use crate::silksong_memory::Env;
+
+/*
+SaveSlotCompletionIcons.CompletionState is a flags enum:
+```
+[Flags]
+public enum CompletionState
+{
+    None = 0,
+    Act2Regular = 1,
+    Act2Cursed = 2,
+    Act2SoulSnare = 4,
+    Act3Ending = 8,
+}
+```
+CompletedEndings has a flag for every ending completed on the save file,
+LastCompletedEnding has the flag of the most recent one.
+*/
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Ending {
+    WeaverQueen,
+    TwistedChild,
+    SnaredSilk,
+    SisterOfTheVoid,
+}
+
+impl Ending {
+    pub const ALL: [Ending; 4] = [
+        Ending::WeaverQueen,
+        Ending::TwistedChild,
+        Ending::SnaredSilk,
+        Ending::SisterOfTheVoid,
+    ];
+
+    fn flag(self) -> i32 {
+        match self {
+            Ending::WeaverQueen => 1,
+            Ending::TwistedChild => 2,
+            Ending::SnaredSilk => 4,
+            Ending::SisterOfTheVoid => 8,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Ending::WeaverQueen => "Weaver Queen",
+            Ending::TwistedChild => "Twisted Child",
+            Ending::SnaredSilk => "Snared Silk",
+            Ending::SisterOfTheVoid => "Sister of the Void",
+        }
+    }
+
+    /// Whether the ending is one of the flags in a CompletionState
+    pub fn is_in(self, state: i32) -> bool {
+        state & self.flag() != 0
+    }
+
+    /// The ending a single-flag CompletionState stands for
+    pub fn from_state(state: i32) -> Option<Ending> {
+        Ending::ALL.into_iter().find(|e| e.flag() == state)
+    }
+}
+
+pub fn get_latest_ending(e: &Env) -> Option<Ending> {
+    Ending::from_state(e.mem.deref(&e.pd.last_completed_ending).ok()?)
+}
@@ -1,13 +1,29 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 extern crate alloc;
 
 #[global_allocator]
 static ALLOC: dlmalloc::GlobalDlmalloc = dlmalloc::GlobalDlmalloc;
 
+mod act;
+mod area;
+mod breakdown;
+mod checkpoint;
+mod collections;
+mod completion;
+mod crests;
+mod currency;
+mod endings;
+mod load_removal;
+mod play_time;
+mod scene_catalog;
+mod scene_history;
 mod silksong_memory;
+mod split_entry;
 pub mod splits;
+mod steel_soul;
 mod store;
 mod timer;
+mod transition;
 
 use alloc::{boxed::Box, format, string::String, vec::Vec};
 #[cfg(feature = "split-index")]
@@ -18,6 +34,8 @@ use asr::{
         gui::{Title, Widget},
         Gui,
     },
+    time::Duration,
+    time_util::Instant,
     timer::TimerState,
     Address64, Process,
 };
@@ -29,15 +47,26 @@ use ugly_widget::{
 };
 
 use crate::{
+    act::get_act,
+    breakdown::{set_breakdown_variables, Breakdown},
+    checkpoint::Checkpoint,
+    collections::Collection,
+    crests::crest_name,
+    currency::{rosaries_string, shell_shards_string},
+    endings::get_latest_ending,
+    load_removal::{
+        pause_reason_name, LoadRemoval, LoadSignals, PauseReason, PauseReasonTimes, TimingProfile,
+    },
+    play_time::{GameTimeSource, PlayTimeClock},
     silksong_memory::{
-        attach_silksong, get_any_slot_black_threaded, get_game_state, get_health,
-        is_menu_state_save_profiles, Env, GameManagerPointers, Memory, PlayerDataPointers,
-        SceneStore, GAME_STATE_CUTSCENE, GAME_STATE_ENTERING_LEVEL, GAME_STATE_EXITING_LEVEL,
-        GAME_STATE_INACTIVE, GAME_STATE_LOADING, GAME_STATE_MAIN_MENU, GAME_STATE_PLAYING,
-        HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL, MENU_TITLE, NON_MENU_GAME_STATES,
-        OPENING_SCENES, QUIT_TO_MENU, UI_STATE_CUTSCENE, UI_STATE_MAIN_MENU, UI_STATE_PAUSED,
-        UI_STATE_PLAYING,
+        attach_silksong, get_any_slot_black_threaded, get_boss_rush_mode, get_game_state, get_geo,
+        get_health, get_permadeath_mode, is_menu, is_menu_state_save_profiles, Env,
+        GameManagerPointers, Memory, PlayerDataPointers, SceneStore, GAME_STATE_CUTSCENE,
+        GAME_STATE_MAIN_MENU, GAME_STATE_PLAYING, MENU_TITLE, NON_MENU_GAME_STATES,
+        PERMADEATH_MODE_DEAD, PERMA_DEATH, UI_STATE_CUTSCENE, UI_STATE_MAIN_MENU, UI_STATE_PAUSED,
     },
+    split_entry::SplitEntry,
+    steel_soul::{FileMode, SteelSoulDeath},
     store::Store,
     timer::SplitterAction,
 };
@@ -99,10 +128,9 @@ struct AutoSplitterState {
     split_index: Option<u64>,
     segments_splitted: Vec<bool>,
     black_threaded_file_select: bool,
-    look_for_teleporting: bool,
+    load_removal: LoadRemoval,
     #[cfg(debug_assertions)]
     last_ui_state: i32,
-    last_game_state: i32,
     #[cfg(debug_assertions)]
     last_hero_transition_state: i32,
     hits: i64,
@@ -113,10 +141,19 @@ struct AutoSplitterState {
     segment_deaths: Vec<i64>,
     cumulative_deaths: Vec<i64>,
     comparison_deaths: Vec<i64>,
+    /// Whether the counters changed since the last checkpoint
+    checkpoint_dirty: bool,
     last_recoil: bool,
     last_hazard: bool,
     last_health_0: bool,
-    mms_room_dupe: bool,
+    pause_reason: Option<PauseReason>,
+    pause_reason_times: PauseReasonTimes,
+    /// When load removal last ran while the timer was running
+    last_load_removal: Option<Instant>,
+    breakdown: Breakdown,
+    play_time: PlayTimeClock,
+    /// The game's own `completionPercentage`, kept to cross-check the live percent
+    game_percent: Option<i32>,
     #[cfg(debug_assertions)]
     last_health: Option<i32>,
     #[cfg(debug_assertions)]
@@ -134,16 +171,15 @@ impl AutoSplitterState {
         segments_splitted.resize(split_index.unwrap_or_default() as usize, false);
         let comparison_hits = Settings::get_comparison_hits().unwrap_or_default();
         let comparison_deaths = Settings::get_comparison_deaths().unwrap_or_default();
-        AutoSplitterState {
+        let mut state = AutoSplitterState {
             store,
             timer_state,
             split_index,
             segments_splitted,
             black_threaded_file_select: false,
-            look_for_teleporting: false,
+            load_removal: LoadRemoval::new(),
             #[cfg(debug_assertions)]
             last_ui_state: 0,
-            last_game_state: GAME_STATE_INACTIVE,
             #[cfg(debug_assertions)]
             last_hero_transition_state: 0,
             hits: 0,
@@ -154,15 +190,25 @@ impl AutoSplitterState {
             segment_deaths: Vec::new(),
             cumulative_deaths: Vec::new(),
             comparison_deaths,
+            checkpoint_dirty: false,
             last_recoil: false,
             last_hazard: false,
             last_health_0: false,
-            mms_room_dupe: false,
+            pause_reason: None,
+            pause_reason_times: PauseReasonTimes::new(),
+            last_load_removal: None,
+            breakdown: Breakdown::new(),
+            play_time: PlayTimeClock::new(),
+            game_percent: None,
             #[cfg(debug_assertions)]
             last_health: None,
             #[cfg(debug_assertions)]
             last_paused: false,
+        };
+        if timer_state == TimerState::Running || timer_state == TimerState::Paused {
+            state.load_checkpoint();
         }
+        state
     }
 
     fn update(&mut self, settings: &Settings, env: Option<&Env>) {
@@ -211,38 +257,7 @@ impl AutoSplitterState {
                 {
                     self.split_index = None;
                 }
-                self.segments_splitted.clear();
-                self.hits = 0;
-                self.segment_hits.clear();
-                self.cumulative_hits.clear();
-                self.deaths = 0;
-                self.segment_deaths.clear();
-                self.cumulative_deaths.clear();
-                if settings.get_hit_counter() {
-                    asr::timer::set_variable_int("hits", self.hits);
-                    asr::timer::set_variable_int("segment hits", 0);
-                } else {
-                    asr::timer::set_variable("hits", DASH);
-                    asr::timer::set_variable("segment hits", DASH);
-                }
-                if settings.get_death_counter() {
-                    asr::timer::set_variable_int("deaths", self.deaths);
-                    asr::timer::set_variable_int("segment deaths", 0);
-                } else {
-                    asr::timer::set_variable("deaths", DASH);
-                    asr::timer::set_variable("segment deaths", DASH);
-                }
-                asr::timer::set_variable("comparison hits", DASH);
-                asr::timer::set_variable("delta hits", DASH);
-                asr::timer::set_variable("comparison deaths", DASH);
-                asr::timer::set_variable("delta deaths", DASH);
-                asr::timer::set_variable("percent", DASH);
-                self.look_for_teleporting = false;
-                self.last_game_state = GAME_STATE_INACTIVE;
-                #[cfg(debug_assertions)]
-                {
-                    self.last_paused = false;
-                }
+                self.clear_run(settings);
             }
             TimerState::Running if is_timer_state_between_runs(self.timer_state) => {
                 // Start
@@ -253,26 +268,8 @@ impl AutoSplitterState {
                 let new_i = new_index as usize;
                 self.segment_hits.resize(new_i + 1, 0);
                 self.segment_deaths.resize(new_i + 1, 0);
-                if settings.get_hit_counter() {
-                    asr::timer::set_variable_int("segment hits", self.segment_hits[new_i]);
-                    if let Some(c) = self.comparison_hits.get(new_i) {
-                        asr::timer::set_variable_int("comparison hits", *c);
-                        asr::timer::set_variable("delta hits", &delta_string(self.hits - c));
-                    } else {
-                        asr::timer::set_variable("comparison hits", DASH);
-                        asr::timer::set_variable("delta hits", DASH);
-                    }
-                }
-                if settings.get_death_counter() {
-                    asr::timer::set_variable_int("segment deaths", self.segment_deaths[new_i]);
-                    if let Some(c) = self.comparison_deaths.get(new_i) {
-                        asr::timer::set_variable_int("comparison deaths", *c);
-                        asr::timer::set_variable("delta deaths", &delta_string(self.deaths - c));
-                    } else {
-                        asr::timer::set_variable("comparison deaths", DASH);
-                        asr::timer::set_variable("delta deaths", DASH);
-                    }
-                }
+                self.set_segment_variables(settings, new_i);
+                self.store.start_areas();
                 // InitializeGameTime
                 asr::timer::pause_game_time();
                 asr::timer::resume_game_time();
@@ -382,35 +379,7 @@ impl AutoSplitterState {
                     }
 
                     if new_index != old_index {
-                        if settings.get_hit_counter() {
-                            asr::timer::set_variable_int("segment hits", self.segment_hits[new_i]);
-                            if let Some(c) = self.comparison_hits.get(new_i) {
-                                asr::timer::set_variable_int("comparison hits", *c);
-                                asr::timer::set_variable(
-                                    "delta hits",
-                                    &delta_string(self.hits - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison hits", DASH);
-                                asr::timer::set_variable("delta hits", DASH);
-                            }
-                        }
-                        if settings.get_death_counter() {
-                            asr::timer::set_variable_int(
-                                "segment deaths",
-                                self.segment_deaths[new_i],
-                            );
-                            if let Some(c) = self.comparison_deaths.get(new_i) {
-                                asr::timer::set_variable_int("comparison deaths", *c);
-                                asr::timer::set_variable(
-                                    "delta deaths",
-                                    &delta_string(self.deaths - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison deaths", DASH);
-                                asr::timer::set_variable("delta deaths", DASH);
-                            }
-                        }
+                        self.set_segment_variables(settings, new_i);
                     }
                 }
             }
@@ -421,6 +390,151 @@ impl AutoSplitterState {
         if let Some(p) = index_pair {
             self.split_index = p.current;
         }
+        self.store_checkpoint();
+    }
+
+    /// Clears the counters for the run, on a reset
+    fn clear_run(&mut self, settings: &Settings) {
+        if !self.breakdown.is_empty() {
+            Settings::push_attempt_breakdown(&self.breakdown.run_seconds());
+        }
+        self.breakdown.clear();
+        self.play_time.clear();
+        if settings.get_timing_breakdown() {
+            set_breakdown_variables(DASH);
+        }
+        self.segments_splitted.clear();
+        self.hits = 0;
+        self.segment_hits.clear();
+        self.cumulative_hits.clear();
+        self.deaths = 0;
+        self.segment_deaths.clear();
+        self.cumulative_deaths.clear();
+        if settings.get_hit_counter() {
+            asr::timer::set_variable_int("hits", self.hits);
+            asr::timer::set_variable_int("segment hits", 0);
+        } else {
+            asr::timer::set_variable("hits", DASH);
+            asr::timer::set_variable("segment hits", DASH);
+        }
+        if settings.get_death_counter() {
+            asr::timer::set_variable_int("deaths", self.deaths);
+            asr::timer::set_variable_int("segment deaths", 0);
+        } else {
+            asr::timer::set_variable("deaths", DASH);
+            asr::timer::set_variable("segment deaths", DASH);
+        }
+        asr::timer::set_variable("comparison hits", DASH);
+        asr::timer::set_variable("delta hits", DASH);
+        asr::timer::set_variable("comparison deaths", DASH);
+        asr::timer::set_variable("delta deaths", DASH);
+        asr::timer::set_variable("percent", DASH);
+        for collection in Collection::ALL {
+            asr::timer::set_variable(collection.name(), DASH);
+        }
+        asr::timer::set_variable("rosaries", DASH);
+        asr::timer::set_variable("shell shards", DASH);
+        asr::timer::set_variable("journal", DASH);
+        asr::timer::set_variable("relics", DASH);
+        asr::timer::set_variable("mementos", DASH);
+        asr::timer::set_variable("current crest", DASH);
+        asr::timer::set_variable("latest ending", DASH);
+        asr::timer::set_variable("current act", DASH);
+        asr::timer::set_variable("area", DASH);
+        asr::timer::set_variable("rooms visited", DASH);
+        asr::timer::set_variable("last transition", DASH);
+        asr::timer::set_variable("last gate", DASH);
+        self.store.clear_areas();
+        self.store.clear_crest_swaps();
+        self.store.clear_spent();
+        if settings.get_missing_items() {
+            asr::timer::set_variable("missing", DASH);
+        }
+        self.game_percent = None;
+        asr::timer::set_variable("load reason", DASH);
+        self.pause_reason = None;
+        self.pause_reason_times.clear();
+        if settings.get_load_reason_times() {
+            for reason in PauseReason::ALL {
+                asr::timer::set_variable(reason.time_variable(), DASH);
+            }
+        }
+        self.load_removal.clear();
+        #[cfg(debug_assertions)]
+        {
+            self.last_paused = false;
+        }
+    }
+
+    /// Sets the segment, comparison, and delta variables for segment `i`
+    fn set_segment_variables(&self, settings: &Settings, i: usize) {
+        if settings.get_hit_counter() {
+            asr::timer::set_variable_int("segment hits", self.segment_hits[i]);
+            if let Some(c) = self.comparison_hits.get(i) {
+                asr::timer::set_variable_int("comparison hits", *c);
+                asr::timer::set_variable("delta hits", &delta_string(self.hits - c));
+            } else {
+                asr::timer::set_variable("comparison hits", DASH);
+                asr::timer::set_variable("delta hits", DASH);
+            }
+        }
+        if settings.get_death_counter() {
+            asr::timer::set_variable_int("segment deaths", self.segment_deaths[i]);
+            if let Some(c) = self.comparison_deaths.get(i) {
+                asr::timer::set_variable_int("comparison deaths", *c);
+                asr::timer::set_variable("delta deaths", &delta_string(self.deaths - c));
+            } else {
+                asr::timer::set_variable("comparison deaths", DASH);
+                asr::timer::set_variable("delta deaths", DASH);
+            }
+        }
+    }
+
+    /// The in-progress counters, as they would be saved by `store_checkpoint`
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            split_index: self.split_index,
+            segments_splitted: self.segments_splitted.clone(),
+            hits: self.hits,
+            segment_hits: self.segment_hits.clone(),
+            cumulative_hits: self.cumulative_hits.clone(),
+            deaths: self.deaths,
+            segment_deaths: self.segment_deaths.clone(),
+            cumulative_deaths: self.cumulative_deaths.clone(),
+            play_time: Some(self.play_time.total()),
+        }
+    }
+
+    /// Saves the in-progress counters into the settings map,
+    /// so that they survive the auto splitter being reloaded mid-run
+    fn store_checkpoint(&mut self) {
+        self.checkpoint_dirty = false;
+        self.checkpoint().store();
+    }
+
+    /// Restores the counters saved by `store_checkpoint`,
+    /// if they were saved at the same split index the timer is at now
+    fn load_checkpoint(&mut self) {
+        let Some(index) = self.split_index else {
+            return;
+        };
+        let Some(checkpoint) = Checkpoint::load().restore_at(index) else {
+            return;
+        };
+        self.segments_splitted = checkpoint.segments_splitted;
+        self.hits = checkpoint.hits;
+        self.segment_hits = checkpoint.segment_hits;
+        self.cumulative_hits = checkpoint.cumulative_hits;
+        self.deaths = checkpoint.deaths;
+        self.segment_deaths = checkpoint.segment_deaths;
+        self.cumulative_deaths = checkpoint.cumulative_deaths;
+        if let Some(t) = checkpoint.play_time {
+            self.play_time.restore(t);
+        }
+        asr::print_message(&format!(
+            "Restored checkpoint at split index {}: hits {}, deaths {}",
+            index, self.hits, self.deaths
+        ));
     }
 }
 
@@ -446,9 +560,44 @@ impl Widget for PkgVersion {
 #[derive(Gui)]
 struct Settings {
     _pkg_version: PkgVersion,
-    /// Pause GameTime on File Select (Required Off for Glitched)
-    #[default = true]
-    pause_on_file_select: bool,
+    /// GameTime Source
+    ///
+    /// Load Removal pauses GameTime during loads.
+    /// In-game Play Time follows the game's own play time counter instead
+    game_time_source: GameTimeSource,
+    /// Timing Profile
+    ///
+    /// Which clauses of load removal pause GameTime.
+    /// Only Standard IGT pauses on File Select, Glitched and RTA-no-loads never do
+    timing_profile: TimingProfile,
+    /// Count GameTime while the game is closed or crashed
+    #[default = false]
+    count_crash_time: bool,
+    /// Load Reason Times
+    #[default = false]
+    load_reason_times: bool,
+    /// Timing Breakdown: Loads, Pause Menu, Inventory, and Cutscenes
+    #[default = false]
+    timing_breakdown: bool,
+    /// Missing Items
+    ///
+    /// Lists the remaining 100% items in the `missing` variable
+    #[default = false]
+    missing_items: bool,
+    /// File Mode
+    ///
+    /// Only auto-starts on save files of this mode
+    file_mode: FileMode,
+    /// On Steel Soul Death
+    ///
+    /// Ends or resets the run when a Steel Soul character permanently dies.
+    /// Ending skips any splits left before the last one
+    steel_soul_death: SteelSoulDeath,
+    /// Boss Rush: Reset on Death
+    ///
+    /// Resets the run when Hornet dies during a boss rush sequence
+    #[default = false]
+    boss_rush_reset_on_death: bool,
     /// Hit Counter
     #[default = true]
     hit_counter: bool,
@@ -457,7 +606,7 @@ struct Settings {
     death_counter: bool,
     /// Splits
     #[heading_level = 1]
-    splits: UglyList<splits::Split>,
+    splits: UglyList<SplitEntry>,
 }
 
 impl StoreGui for Settings {
@@ -470,8 +619,32 @@ impl StoreGui for Settings {
 }
 
 impl Settings {
-    pub fn get_pause_on_file_select(&self) -> bool {
-        self.pause_on_file_select
+    pub fn get_game_time_source(&self) -> GameTimeSource {
+        self.game_time_source
+    }
+    pub fn get_timing_profile(&self) -> TimingProfile {
+        self.timing_profile
+    }
+    pub fn get_count_crash_time(&self) -> bool {
+        self.count_crash_time
+    }
+    pub fn get_load_reason_times(&self) -> bool {
+        self.load_reason_times
+    }
+    pub fn get_timing_breakdown(&self) -> bool {
+        self.timing_breakdown
+    }
+    pub fn get_missing_items(&self) -> bool {
+        self.missing_items
+    }
+    pub fn get_file_mode(&self) -> FileMode {
+        self.file_mode
+    }
+    pub fn get_steel_soul_death(&self) -> SteelSoulDeath {
+        self.steel_soul_death
+    }
+    pub fn get_boss_rush_reset_on_death(&self) -> bool {
+        self.boss_rush_reset_on_death
     }
     pub fn get_hit_counter(&self) -> bool {
         self.hit_counter
@@ -483,10 +656,14 @@ impl Settings {
         self.splits.get_list().len()
     }
     pub fn get_splits(&self) -> Vec<splits::Split> {
-        self.splits.get_list().into_iter().cloned().collect()
+        self.splits
+            .get_list()
+            .into_iter()
+            .map(|entry| entry.split.clone())
+            .collect()
     }
-    pub fn get_split(&self, i: u64) -> Option<splits::Split> {
-        self.splits.get_list().get(i as usize).cloned().cloned()
+    pub fn get_split(&self, i: u64) -> Option<&SplitEntry> {
+        self.splits.get_list().get(i as usize).copied()
     }
 
     pub fn default_init_register() -> Settings {
@@ -528,6 +705,28 @@ impl Settings {
         Settings::insert("comparison_deaths", &l);
     }
 
+    /// Records the breakdown of an attempt, keeping the most recent attempts
+    fn push_attempt_breakdown(seconds: &[f64]) {
+        const MAX_ATTEMPTS: usize = 100;
+        let attempt = settings_list(seconds);
+        loop {
+            let old = asr::settings::Map::load();
+            let new = old.clone();
+            let attempts = asr::settings::List::new();
+            if let Some(l) = old.get("attempt_breakdowns").and_then(|v| v.get_list()) {
+                let skip = (l.len() + 1).saturating_sub(MAX_ATTEMPTS);
+                for a in l.iter().skip(skip) {
+                    attempts.push(a);
+                }
+            }
+            attempts.push(&attempt);
+            new.insert("attempt_breakdowns", &attempts);
+            if new.store_if_unchanged(&old) {
+                return;
+            }
+        }
+    }
+
     fn insert<T: asr::settings::AsValue + Copy>(key: &str, value: T) {
         loop {
             let old = asr::settings::Map::load();
@@ -592,6 +791,13 @@ fn asr_settings_normalize(m: &asr::settings::Map) -> Option<()> {
         changed = true;
         m.insert("script_name", this_script);
     }
+    // Pause GameTime on File Select used to be its own setting, before timing profiles
+    if m.get("timing_profile").is_none()
+        && m.get("pause_on_file_select").and_then(|v| v.get_bool()) == Some(false)
+    {
+        changed = true;
+        m.insert("timing_profile", "Glitched");
+    }
     for (i, old_split) in old_splits.iter().enumerate() {
         let old_string = old_split.get_string()?;
         let new_string = options_normalize::<splits::Split>(&old_string);
@@ -624,6 +830,7 @@ async fn main() {
     asr::timer::set_variable("comparison deaths", DASH);
     asr::timer::set_variable("delta deaths", DASH);
     asr::timer::set_variable("percent", DASH);
+    asr::timer::set_variable("load reason", DASH);
 
     asr::print_message("Hello, World!");
 
@@ -636,18 +843,38 @@ async fn main() {
     ));
     asr::print_message(&format!("splits: {:?}", settings.get_splits()));
 
+    if settings.get_load_reason_times() {
+        for reason in PauseReason::ALL {
+            asr::timer::set_variable(reason.time_variable(), DASH);
+        }
+    }
+
+    if settings.get_timing_breakdown() {
+        set_breakdown_variables(DASH);
+    }
+
+    if settings.get_missing_items() {
+        asr::timer::set_variable("missing", DASH);
+    }
+
     let mut state = AutoSplitterState::new();
 
     if settings.get_hit_counter() {
-        asr::timer::set_variable_int("hits", 0);
+        asr::timer::set_variable_int("hits", state.hits);
         asr::timer::set_variable_int("segment hits", 0);
     }
 
     if settings.get_death_counter() {
-        asr::timer::set_variable_int("deaths", 0);
+        asr::timer::set_variable_int("deaths", state.deaths);
         asr::timer::set_variable_int("segment deaths", 0);
     }
 
+    if let Some(i) = state.split_index {
+        if (i as usize) < state.segment_hits.len() {
+            state.set_segment_variables(&settings, i as usize);
+        }
+    }
+
     if !state.comparison_hits.is_empty()
         && (state.comparison_hits.len() + 1 == settings.get_splits_len())
     {
@@ -673,7 +900,11 @@ async fn main() {
                 // TODO: Load some initial information from the process.
                 let mut scene_store = Box::new(SceneStore::new());
                 next_tick().await;
-                let mem = Memory::wait_attach(&process).await;
+                // the game is not playing until the Mono image resolves
+                let mem = Memory::wait_attach(&process, || {
+                    update_while_detached(&mut settings, &mut state);
+                })
+                .await;
                 next_tick().await;
                 let gm = Box::new(GameManagerPointers::new());
                 let pd = Box::new(PlayerDataPointers::new());
@@ -711,6 +942,11 @@ async fn main() {
                     .unwrap_or_default();
                 let _: Address64 = mem.deref(&pd.version).unwrap_or_default();
                 let _: bool = mem.deref(&pd.is_inventory_open).unwrap_or_default();
+                let _: bool = mem.deref(&pd.is_teleporting).unwrap_or_default();
+                let _: bool = mem.deref(&gm.hazard_respawning_hero).unwrap_or_default();
+                let _: bool = mem.deref(&gm.is_in_scene_transition).unwrap_or_default();
+                let _: bool = mem.deref(&gm.is_loading).unwrap_or_default();
+                let _: f32 = mem.deref(&gm.current_load_duration).unwrap_or_default();
                 let _: i32 = mem.deref(&pd.health).unwrap_or_default();
                 state
                     .store
@@ -729,6 +965,9 @@ async fn main() {
                     {
                         settings.load_update_store_if_unchanged();
                         ticks_since_gui = 0;
+                        if state.checkpoint_dirty {
+                            state.store_checkpoint();
+                        }
                     }
                     state.update(&settings, Some(&env));
 
@@ -736,7 +975,15 @@ async fn main() {
                     handle_splits(&settings, &mut state, &env, &mut scene_store).await;
                     load_removal(&settings, &mut state, &env);
                     handle_hits(&settings, &mut state, &env);
-                    handle_percent(&mem, &gm, &pd);
+                    handle_percent(&settings, &mut state, &env);
+                    handle_collections(&env);
+                    handle_journal(&mut state, &env);
+                    handle_relics(&mut state, &env);
+                    handle_crest(&state, &env);
+                    handle_ending(&env);
+                    handle_act(&env);
+                    handle_area(&state);
+                    handle_currency(&mut state, &env, &scene_store);
                     next_tick().await;
                 }
             })
@@ -746,15 +993,83 @@ async fn main() {
 
 async fn wait_attach_silksong(gui: &mut Settings, state: &mut AutoSplitterState) -> Process {
     retry(|| {
-        gui.load_update_store_if_unchanged();
-        state.update(gui, None);
+        update_while_detached(gui, state);
         attach_silksong()
     })
     .await
 }
 
+/// Keeps the settings and timer state up to date while there is no memory to read,
+/// pausing game time since the game can't be playing
+fn update_while_detached(gui: &mut Settings, state: &mut AutoSplitterState) {
+    gui.load_update_store_if_unchanged();
+    state.update(gui, None);
+    pause_while_detached(gui, state);
+}
+
+fn pause_while_detached(settings: &Settings, state: &mut AutoSplitterState) {
+    // only pause if timer is running
+    if asr::timer::state() != TimerState::Running {
+        return;
+    }
+    let was_detached = state.load_removal.detached;
+    let pause_reason = state.load_removal.detach(settings.get_count_crash_time());
+    if pause_reason.is_some() {
+        asr::timer::pause_game_time();
+        if !was_detached {
+            asr::print_message("Detached: pausing GameTime until the game is playing again");
+        }
+    } else {
+        // crash time counts, so don't leave GameTime paused from a load when it crashed
+        asr::timer::resume_game_time();
+    }
+    if pause_reason != state.pause_reason {
+        asr::timer::set_variable("load reason", pause_reason_name(pause_reason));
+        state.pause_reason = pause_reason;
+    }
+}
+
 // --------------------------------------------------------
 
+/// Whether a Steel Soul character permanently died this tick
+fn steel_soul_died(
+    state: &mut AutoSplitterState,
+    env: &Env,
+    ss: &SceneStore,
+    trans_now: bool,
+) -> bool {
+    let mode_dead = state
+        .store
+        .get_i32_pair_bang("permadeath_mode", &get_permadeath_mode, Some(env))
+        .is_some_and(|p| p.changed_to(&PERMADEATH_MODE_DEAD));
+    mode_dead || (trans_now && ss.pair().current == PERMA_DEATH)
+}
+
+/// Whether the split entry can split now, within its limits
+fn split_limit_allows(
+    entry: &SplitEntry,
+    state: &AutoSplitterState,
+    env: &Env,
+    ss: &SceneStore,
+    trans_now: bool,
+) -> bool {
+    entry
+        .act
+        .act()
+        .is_none_or(|act| get_act(env).is_ok_and(|a| a == act))
+        && (entry.gate.is_empty() || (trans_now && ss.entry_gate() == entry.gate))
+        && (!entry.first_visit || (trans_now && state.store.get_area().entered_new_scene()))
+}
+
+/// Whether Hornet died during a boss rush sequence this tick
+fn boss_rush_died(state: &mut AutoSplitterState, env: &Env) -> bool {
+    get_boss_rush_mode(Some(env)).unwrap_or_default()
+        && state
+            .store
+            .get_i32_pair_bang("health", &get_health, Some(env))
+            .is_some_and(|p| p.changed_to(&0))
+}
+
 async fn handle_splits(
     settings: &Settings,
     state: &mut AutoSplitterState,
@@ -768,17 +1083,37 @@ async fn handle_splits(
             "Opening_Sequence_Act3" => state.black_threaded_file_select = false,
             _ => (),
         }
+        if !is_timer_state_between_runs(state.timer_state) {
+            asr::timer::set_variable("last transition", ss.transition_kind().name());
+            asr::timer::set_variable("last gate", ss.entry_gate());
+        }
+        asr::timer::set_variable("recent rooms", &ss.history().recent_string(5));
     }
+    state.store.update_area(trans_now, ss.pair().current, env);
+    let perma_dead = steel_soul_died(state, env, ss, trans_now);
+    let boss_rush_dead = boss_rush_died(state, env);
+    let mut acted = false;
     loop {
         match state.timer_state {
             TimerState::NotRunning => {
                 ss.split_this_transition = false;
                 // TODO: look up from settings
-                let Some(split) = settings.get_split(0) else {
+                let Some(entry) = settings.get_split(0) else {
                     break;
                 };
-                let a = splits::splits(&split, env, trans_now, ss, &mut state.store);
+                let a = if split_limit_allows(entry, state, env, ss, trans_now) {
+                    splits::splits(entry, env, trans_now, ss, &mut state.store)
+                } else {
+                    None
+                };
                 match a {
+                    Some(SplitterAction::Split)
+                        if !settings
+                            .get_file_mode()
+                            .allows(get_permadeath_mode(Some(env))) =>
+                    {
+                        break;
+                    }
                     Some(SplitterAction::Split) => {
                         // Start
                         asr::timer::start();
@@ -786,37 +1121,12 @@ async fn handle_splits(
                         state.split_index = Some(0);
                         state.segment_hits.resize(1, 0);
                         state.segment_deaths.resize(1, 0);
-                        if settings.get_hit_counter() {
-                            asr::timer::set_variable_int("segment hits", state.segment_hits[0]);
-                            // .first() = .get(0)
-                            if let Some(c) = state.comparison_hits.first() {
-                                asr::timer::set_variable_int("comparison hits", *c);
-                                asr::timer::set_variable(
-                                    "delta hits",
-                                    &delta_string(state.hits - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison hits", DASH);
-                                asr::timer::set_variable("delta hits", DASH);
-                            }
-                        }
-                        if settings.get_death_counter() {
-                            asr::timer::set_variable_int("segment deaths", state.segment_deaths[0]);
-                            // .first() = .get(0)
-                            if let Some(c) = state.comparison_deaths.first() {
-                                asr::timer::set_variable_int("comparison deaths", *c);
-                                asr::timer::set_variable(
-                                    "delta deaths",
-                                    &delta_string(state.deaths - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison deaths", DASH);
-                                asr::timer::set_variable("delta deaths", DASH);
-                            }
-                        }
+                        state.set_segment_variables(settings, 0);
+                        state.store.start_areas();
                         // InitializeGameTime
                         asr::timer::pause_game_time();
                         asr::timer::resume_game_time();
+                        acted = true;
                         break;
                     }
                     _ => break,
@@ -824,11 +1134,26 @@ async fn handle_splits(
             }
             TimerState::Running | TimerState::Paused => {
                 // TODO: look up from settings
-                let Some(split) = settings.get_split(state.split_index.unwrap_or_default() + 1)
-                else {
+                let next_index = state.split_index.unwrap_or_default() + 1;
+                let Some(entry) = settings.get_split(next_index) else {
                     break;
                 };
-                let a = splits::splits(&split, env, trans_now, ss, &mut state.store);
+                let a = match settings.get_steel_soul_death() {
+                    SteelSoulDeath::End if perma_dead => {
+                        // splits_len = number_of_segments + 1
+                        if next_index as usize + 1 < settings.get_splits_len() {
+                            Some(SplitterAction::Skip)
+                        } else {
+                            Some(SplitterAction::Split)
+                        }
+                    }
+                    SteelSoulDeath::Reset if perma_dead => Some(SplitterAction::Reset),
+                    _ if boss_rush_dead && settings.get_boss_rush_reset_on_death() => {
+                        Some(SplitterAction::Reset)
+                    }
+                    _ if !split_limit_allows(entry, state, env, ss, trans_now) => None,
+                    _ => splits::splits(entry, env, trans_now, ss, &mut state.store),
+                };
                 match a {
                     Some(SplitterAction::Reset) => {
                         if settings.get_hit_counter() {
@@ -846,38 +1171,8 @@ async fn handle_splits(
                         asr::timer::reset();
                         state.timer_state = TimerState::NotRunning;
                         state.split_index = None;
-                        state.segments_splitted.clear();
-                        state.hits = 0;
-                        state.segment_hits.clear();
-                        state.cumulative_hits.clear();
-                        state.deaths = 0;
-                        state.segment_deaths.clear();
-                        state.cumulative_deaths.clear();
-                        if settings.get_hit_counter() {
-                            asr::timer::set_variable_int("hits", state.hits);
-                            asr::timer::set_variable_int("segment hits", 0);
-                        } else {
-                            asr::timer::set_variable("hits", DASH);
-                            asr::timer::set_variable("segment hits", DASH);
-                        }
-                        if settings.get_death_counter() {
-                            asr::timer::set_variable_int("deaths", state.deaths);
-                            asr::timer::set_variable_int("segment deaths", 0);
-                        } else {
-                            asr::timer::set_variable("deaths", DASH);
-                            asr::timer::set_variable("segment deaths", DASH);
-                        }
-                        asr::timer::set_variable("comparison hits", DASH);
-                        asr::timer::set_variable("delta hits", DASH);
-                        asr::timer::set_variable("comparison deaths", DASH);
-                        asr::timer::set_variable("delta deaths", DASH);
-                        asr::timer::set_variable("percent", DASH);
-                        state.look_for_teleporting = false;
-                        state.last_game_state = GAME_STATE_INACTIVE;
-                        #[cfg(debug_assertions)]
-                        {
-                            state.last_paused = false;
-                        }
+                        state.clear_run(settings);
+                        acted = true;
                         // no break, allow other actions after a skip or reset
                     }
                     Some(SplitterAction::Skip) => {
@@ -889,35 +1184,8 @@ async fn handle_splits(
                         state.segments_splitted.push(false);
                         state.segment_hits.insert(old_i, 0);
                         state.segment_deaths.insert(old_i, 0);
-                        if settings.get_hit_counter() {
-                            asr::timer::set_variable_int("segment hits", state.segment_hits[new_i]);
-                            if let Some(c) = state.comparison_hits.get(new_i) {
-                                asr::timer::set_variable_int("comparison hits", *c);
-                                asr::timer::set_variable(
-                                    "delta hits",
-                                    &delta_string(state.hits - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison hits", DASH);
-                                asr::timer::set_variable("delta hits", DASH);
-                            }
-                        }
-                        if settings.get_death_counter() {
-                            asr::timer::set_variable_int(
-                                "segment deaths",
-                                state.segment_deaths[new_i],
-                            );
-                            if let Some(c) = state.comparison_deaths.get(new_i) {
-                                asr::timer::set_variable_int("comparison deaths", *c);
-                                asr::timer::set_variable(
-                                    "delta deaths",
-                                    &delta_string(state.deaths - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison deaths", DASH);
-                                asr::timer::set_variable("delta deaths", DASH);
-                            }
-                        }
+                        state.set_segment_variables(settings, new_i);
+                        acted = true;
                         // no break, allow other actions after a skip or reset
                     }
                     Some(SplitterAction::Split) => {
@@ -930,35 +1198,8 @@ async fn handle_splits(
                         state.cumulative_hits.resize(new_i, state.hits);
                         state.segment_deaths.push(0);
                         state.cumulative_deaths.resize(new_i, state.deaths);
-                        if settings.get_hit_counter() {
-                            asr::timer::set_variable_int("segment hits", state.segment_hits[new_i]);
-                            if let Some(c) = state.comparison_hits.get(new_i) {
-                                asr::timer::set_variable_int("comparison hits", *c);
-                                asr::timer::set_variable(
-                                    "delta hits",
-                                    &delta_string(state.hits - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison hits", DASH);
-                                asr::timer::set_variable("delta hits", DASH);
-                            }
-                        }
-                        if settings.get_death_counter() {
-                            asr::timer::set_variable_int(
-                                "segment deaths",
-                                state.segment_deaths[new_i],
-                            );
-                            if let Some(c) = state.comparison_deaths.get(new_i) {
-                                asr::timer::set_variable_int("comparison deaths", *c);
-                                asr::timer::set_variable(
-                                    "delta deaths",
-                                    &delta_string(state.deaths - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison deaths", DASH);
-                                asr::timer::set_variable("delta deaths", DASH);
-                            }
-                        }
+                        state.set_segment_variables(settings, new_i);
+                        acted = true;
                         break;
                     }
                     Some(SplitterAction::ManualSplit) => {
@@ -975,38 +1216,8 @@ async fn handle_splits(
                             state.segments_splitted.push(false);
                             state.segment_hits.insert(old_i, 0);
                             state.segment_deaths.insert(old_i, 0);
-                            if settings.get_hit_counter() {
-                                asr::timer::set_variable_int(
-                                    "segment hits",
-                                    state.segment_hits[new_i],
-                                );
-                                if let Some(c) = state.comparison_hits.get(new_i) {
-                                    asr::timer::set_variable_int("comparison hits", *c);
-                                    asr::timer::set_variable(
-                                        "delta hits",
-                                        &delta_string(state.hits - c),
-                                    );
-                                } else {
-                                    asr::timer::set_variable("comparison hits", DASH);
-                                    asr::timer::set_variable("delta hits", DASH);
-                                }
-                            }
-                            if settings.get_death_counter() {
-                                asr::timer::set_variable_int(
-                                    "segment deaths",
-                                    state.segment_deaths[new_i],
-                                );
-                                if let Some(c) = state.comparison_deaths.get(new_i) {
-                                    asr::timer::set_variable_int("comparison deaths", *c);
-                                    asr::timer::set_variable(
-                                        "delta deaths",
-                                        &delta_string(state.deaths - c),
-                                    );
-                                } else {
-                                    asr::timer::set_variable("comparison deaths", DASH);
-                                    asr::timer::set_variable("delta deaths", DASH);
-                                }
-                            }
+                            state.set_segment_variables(settings, new_i);
+                            acted = true;
                         }
                         break;
                     }
@@ -1016,14 +1227,25 @@ async fn handle_splits(
             _ => break,
         }
     }
+    if acted {
+        state.store_checkpoint();
+    }
 }
 
 fn load_removal(settings: &Settings, state: &mut AutoSplitterState, e: &Env) {
     // only remove loads if timer is running
     if asr::timer::state() != TimerState::Running {
+        state.last_load_removal = None;
         return;
     }
 
+    // the real time since the last tick of load removal
+    let now = Instant::now();
+    let dt = state
+        .last_load_removal
+        .map_or(Duration::ZERO, |last| now - last);
+    state.last_load_removal = Some(now);
+
     let Env { mem, gm, pd } = e;
 
     let ui_state: i32 = mem.deref(&gm.ui_state_vanilla).unwrap_or_default();
@@ -1032,85 +1254,86 @@ fn load_removal(settings: &Settings, state: &mut AutoSplitterState, e: &Env) {
     let scene_name = mem.read_string(&gm.scene_name).unwrap_or_default();
     let next_scene = mem.read_string(&gm.next_scene_name).unwrap_or_default();
 
-    let loading_menu = (scene_name == QUIT_TO_MENU)
-        || (scene_name != MENU_TITLE && (next_scene.is_empty() || next_scene == MENU_TITLE));
-
-    // TODO: teleporting, look_for_teleporting
-
     let game_state: i32 = mem.deref(&gm.game_state).unwrap_or_default();
-
-    if game_state == GAME_STATE_PLAYING && state.last_game_state == GAME_STATE_MAIN_MENU {
-        state.look_for_teleporting = true;
-    }
-    if state.look_for_teleporting
-        && (game_state != GAME_STATE_PLAYING && game_state != GAME_STATE_ENTERING_LEVEL)
-    {
-        state.look_for_teleporting = false;
-    }
-
-    if game_state == GAME_STATE_LOADING
-        && state.last_game_state == GAME_STATE_CUTSCENE
-        && OPENING_SCENES.contains(&scene_name.as_str())
-    {
-        #[cfg(debug_assertions)]
-        if !state.mms_room_dupe {
-            asr::print_message("mms_room_dupe: true");
-        }
-        state.mms_room_dupe = true;
-    } else if game_state == GAME_STATE_PLAYING {
-        #[cfg(debug_assertions)]
-        if state.mms_room_dupe {
-            asr::print_message("mms_room_dupe: false");
-        }
-        state.mms_room_dupe = false;
-    }
-
-    // TODO: hazard_respawning
-    let accepting_input: bool = mem.deref(&gm.accepting_input).unwrap_or_default();
-    let hero_transition_state: i32 = mem.deref(&gm.hero_transition_state).unwrap_or_default();
-    let scene_load_null: bool = mem
-        .deref(&gm.scene_load)
-        .is_ok_and(|a: Address64| a.is_null());
-    let scene_load_activation_allowed: bool = mem
-        .deref(&gm.scene_load_activation_allowed)
-        .unwrap_or_default();
     let is_inventory_open: bool = mem.deref(&pd.is_inventory_open).unwrap_or_default();
-    // TODO: tile_map_dirty, uses_scene_transition_routine
-
-    let is_game_time_paused = (state.look_for_teleporting)
-        || ((game_state == GAME_STATE_PLAYING || game_state == GAME_STATE_ENTERING_LEVEL)
-            && ui_state != UI_STATE_PLAYING)
-        || (game_state != GAME_STATE_PLAYING
-            && game_state != GAME_STATE_CUTSCENE
-            && ui_state != UI_STATE_CUTSCENE
-            && !accepting_input
-            && !state.mms_room_dupe)
-        || ((game_state == GAME_STATE_EXITING_LEVEL
-            && ui_state != UI_STATE_CUTSCENE
-            && (scene_load_null || scene_load_activation_allowed)
-            && !is_inventory_open
-            && !state.mms_room_dupe)
-            || game_state == GAME_STATE_LOADING)
-        || (hero_transition_state == HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL
-            && !is_inventory_open)
-        || (ui_state != UI_STATE_PLAYING
-            && (loading_menu
-                || (ui_state != UI_STATE_PAUSED
-                    && ui_state != UI_STATE_CUTSCENE
-                    && (!next_scene.is_empty())))
-            && next_scene != scene_name)
-        || (settings.get_pause_on_file_select()
-            && game_state == GAME_STATE_MAIN_MENU
+    let signals = LoadSignals {
+        game_state,
+        ui_state,
+        scene_name: &scene_name,
+        next_scene: &next_scene,
+        teleporting: mem.deref(&pd.is_teleporting).unwrap_or_default(),
+        hazard_respawning: mem.deref(&gm.hazard_respawning_hero).unwrap_or_default(),
+        accepting_input: mem.deref(&gm.accepting_input).unwrap_or_default(),
+        hero_transition_state: mem.deref(&gm.hero_transition_state).unwrap_or_default(),
+        scene_load_null: mem
+            .deref(&gm.scene_load)
+            .is_ok_and(|a: Address64| a.is_null()),
+        scene_load_activation_allowed: mem
+            .deref(&gm.scene_load_activation_allowed)
+            .unwrap_or_default(),
+        is_inventory_open,
+        is_loading: mem.deref(&gm.is_loading).unwrap_or_default(),
+        is_in_scene_transition: mem.deref(&gm.is_in_scene_transition).unwrap_or_default(),
+        current_load_duration: mem.deref(&gm.current_load_duration).unwrap_or_default(),
+        file_select: game_state == GAME_STATE_MAIN_MENU
             && ui_state == UI_STATE_MAIN_MENU
             && scene_name == MENU_TITLE
             && is_menu_state_save_profiles(menu_state, &version)
-            && !get_any_slot_black_threaded(Some(e)).unwrap_or(state.black_threaded_file_select));
-    if is_game_time_paused {
+            && !get_any_slot_black_threaded(Some(e)).unwrap_or(state.black_threaded_file_select),
+    };
+
+    #[cfg(debug_assertions)]
+    let (last_game_state, last_mms_room_dupe) = (
+        state.load_removal.last_game_state(),
+        state.load_removal.mms_room_dupe(),
+    );
+    let was_detached = state.load_removal.detached;
+    let rules = settings.get_timing_profile().pause_rules();
+    let pause_reason = state.load_removal.tick(&rules, &signals);
+
+    if was_detached && !state.load_removal.detached {
+        asr::print_message("Reattached: game is playing again");
+    }
+    #[cfg(debug_assertions)]
+    if last_mms_room_dupe != state.load_removal.mms_room_dupe() {
+        asr::print_message(&format!(
+            "mms_room_dupe: {}",
+            state.load_removal.mms_room_dupe()
+        ));
+    }
+
+    let is_game_time_paused = pause_reason.is_some();
+    if settings.get_game_time_source() == GameTimeSource::PlayTime {
+        play_time_game_time(state, e, game_state, &scene_name);
+    } else if is_game_time_paused {
         asr::timer::pause_game_time();
     } else {
         asr::timer::resume_game_time();
     }
 
+    if pause_reason != state.pause_reason {
+        asr::timer::set_variable("load reason", pause_reason_name(pause_reason));
+        state.pause_reason = pause_reason;
+    }
+    if let Some(reason) = pause_reason {
+        if settings.get_load_reason_times() {
+            state.pause_reason_times.add(reason, dt);
+        }
+    }
+
+    if settings.get_timing_breakdown() {
+        state.breakdown.tick(
+            state.split_index,
+            [
+                is_game_time_paused && ui_state != UI_STATE_PAUSED,
+                ui_state == UI_STATE_PAUSED,
+                is_inventory_open,
+                ui_state == UI_STATE_CUTSCENE || game_state == GAME_STATE_CUTSCENE,
+            ],
+            dt,
+        );
+    }
+
     #[cfg(debug_assertions)]
     {
         if ui_state != state.last_ui_state {
@@ -1120,28 +1343,51 @@ fn load_removal(settings: &Settings, state: &mut AutoSplitterState, e: &Env) {
     }
 
     #[cfg(debug_assertions)]
-    if game_state != state.last_game_state {
+    if game_state != last_game_state {
         asr::print_message(&format!("game_state: {}", game_state));
     }
-    state.last_game_state = game_state;
 
     #[cfg(debug_assertions)]
     {
-        if hero_transition_state != state.last_hero_transition_state {
-            asr::print_message(&format!("hero_transition_state: {}", hero_transition_state));
+        if signals.hero_transition_state != state.last_hero_transition_state {
+            asr::print_message(&format!(
+                "hero_transition_state: {}",
+                signals.hero_transition_state
+            ));
         }
-        state.last_hero_transition_state = hero_transition_state;
+        state.last_hero_transition_state = signals.hero_transition_state;
     }
 
     #[cfg(debug_assertions)]
     {
         if is_game_time_paused != state.last_paused {
-            asr::print_message(&format!("is_game_time_paused: {}", is_game_time_paused));
+            asr::print_message(&format!(
+                "is_game_time_paused: {}, load reason: {}, current_load_duration: {}",
+                is_game_time_paused,
+                pause_reason_name(pause_reason),
+                signals.current_load_duration
+            ));
         }
         state.last_paused = is_game_time_paused;
     }
 }
 
+fn play_time_game_time(state: &mut AutoSplitterState, e: &Env, game_state: i32, scene_name: &str) {
+    // game time only moves when it's set from play time
+    asr::timer::pause_game_time();
+    if game_state == GAME_STATE_MAIN_MENU || is_menu(scene_name) {
+        state.play_time.lose_track();
+        return;
+    }
+    let Ok(play_time) = e.mem.deref::<f32, _>(&e.pd.play_time) else {
+        state.play_time.lose_track();
+        return;
+    };
+    let total = state.play_time.update(play_time);
+    asr::timer::set_game_time(asr::time::Duration::seconds_f64(total));
+    state.checkpoint_dirty = true;
+}
+
 fn handle_hits(settings: &Settings, state: &mut AutoSplitterState, e: &Env) {
     // only count hits if hit counter or death counter is true
     if !(settings.get_hit_counter() || settings.get_death_counter()) {
@@ -1204,6 +1450,7 @@ fn handle_hits(settings: &Settings, state: &mut AutoSplitterState, e: &Env) {
 
 fn add_hit(state: &mut AutoSplitterState) {
     state.hits += 1;
+    state.checkpoint_dirty = true;
     asr::timer::set_variable_int("hits", state.hits);
     let i = state.split_index.unwrap_or_default() as usize;
     state.segment_hits.resize(i + 1, 0);
@@ -1218,6 +1465,7 @@ fn add_hit(state: &mut AutoSplitterState) {
 
 fn add_death(state: &mut AutoSplitterState) {
     state.deaths += 1;
+    state.checkpoint_dirty = true;
     asr::timer::set_variable_int("deaths", state.deaths);
     let i = state.split_index.unwrap_or_default() as usize;
     state.segment_deaths.resize(i + 1, 0);
@@ -1230,23 +1478,211 @@ fn add_death(state: &mut AutoSplitterState) {
     }
 }
 
-fn handle_percent(mem: &Memory, gm: &GameManagerPointers, pd: &PlayerDataPointers) {
+fn handle_percent(settings: &Settings, state: &mut AutoSplitterState, e: &Env) {
     // only update percent if timer is running or paused
     if is_timer_state_between_runs(asr::timer::state()) {
         return;
     }
 
     // only update percent if game state is non-menu
-    let game_state: i32 = mem.deref(&gm.game_state).unwrap_or_default();
+    let game_state: i32 = e.mem.deref(&e.gm.game_state).unwrap_or_default();
     if !NON_MENU_GAME_STATES.contains(&game_state) {
         return;
     }
 
-    let Ok(percent) = mem.deref::<f32, _>(&pd.completion_percentage) else {
+    let completion = state.store.get_completion(e);
+    let percent = completion.percent();
+    asr::timer::set_variable("percent", &format!("{}%", percent));
+    if settings.get_missing_items() {
+        asr::timer::set_variable("missing", &completion.missing_string());
+    }
+
+    // the game only recalculates its own percent on save or inventory open,
+    // so only compare when that changes
+    let Ok(game_percent) = e.mem.deref::<f32, _>(&e.pd.completion_percentage) else {
         return;
     };
+    let game_percent = game_percent as i32;
+    if state.game_percent != Some(game_percent) {
+        state.game_percent = Some(game_percent);
+        if game_percent != percent {
+            asr::print_message(&format!(
+                "Completion: live percent {}% differs from the game's {}%",
+                percent, game_percent
+            ));
+        }
+    }
+}
 
-    asr::timer::set_variable("percent", &format!("{}%", percent));
+fn handle_collections(e: &Env) {
+    // only update collections if timer is running or paused
+    if is_timer_state_between_runs(asr::timer::state()) {
+        return;
+    }
+
+    // only update collections if game state is non-menu
+    let game_state: i32 = e.mem.deref(&e.gm.game_state).unwrap_or_default();
+    if !NON_MENU_GAME_STATES.contains(&game_state) {
+        return;
+    }
+
+    for collection in Collection::ALL {
+        if let Some(count) = collection.count_string(e) {
+            asr::timer::set_variable(collection.name(), &count);
+        }
+    }
+}
+
+fn handle_journal(state: &mut AutoSplitterState, e: &Env) {
+    // only update journal if timer is running or paused
+    if is_timer_state_between_runs(asr::timer::state()) {
+        return;
+    }
+
+    // only update journal if game state is non-menu
+    let game_state: i32 = e.mem.deref(&e.gm.game_state).unwrap_or_default();
+    if !NON_MENU_GAME_STATES.contains(&game_state) {
+        return;
+    }
+
+    let Some(journal) = state.store.get_journal_counts_pair() else {
+        return;
+    };
+    asr::timer::set_variable(
+        "journal",
+        &format!(
+            "{} seen, {} encountered",
+            journal.current.seen, journal.current.encountered
+        ),
+    );
+}
+
+fn handle_crest(state: &AutoSplitterState, e: &Env) {
+    // only update crest if timer is running or paused
+    if is_timer_state_between_runs(asr::timer::state()) {
+        return;
+    }
+
+    // only update crest if game state is non-menu
+    let game_state: i32 = e.mem.deref(&e.gm.game_state).unwrap_or_default();
+    if !NON_MENU_GAME_STATES.contains(&game_state) {
+        return;
+    }
+
+    if let Some(crest) = state.store.get_crest().current() {
+        asr::timer::set_variable("current crest", crest_name(crest));
+    }
+}
+
+fn handle_act(e: &Env) {
+    // only update act if timer is running or paused
+    if is_timer_state_between_runs(asr::timer::state()) {
+        return;
+    }
+
+    // only update act if game state is non-menu
+    let game_state: i32 = e.mem.deref(&e.gm.game_state).unwrap_or_default();
+    if !NON_MENU_GAME_STATES.contains(&game_state) {
+        return;
+    }
+
+    if let Ok(act) = get_act(e) {
+        asr::timer::set_variable("current act", act.name());
+    }
+}
+
+fn handle_area(state: &AutoSplitterState) {
+    // only update area if timer is running or paused
+    if is_timer_state_between_runs(asr::timer::state()) {
+        return;
+    }
+
+    let area = state.store.get_area();
+    if area.changed() {
+        if let Some(name) = area.current() {
+            asr::timer::set_variable("area", name);
+        }
+    }
+    if area.entered_new_scene() {
+        asr::timer::set_variable_int("rooms visited", area.rooms_visited() as i64);
+    }
+}
+
+fn handle_ending(e: &Env) {
+    // only update ending if timer is running or paused
+    if is_timer_state_between_runs(asr::timer::state()) {
+        return;
+    }
+
+    // only update ending if game state is non-menu
+    let game_state: i32 = e.mem.deref(&e.gm.game_state).unwrap_or_default();
+    if !NON_MENU_GAME_STATES.contains(&game_state) {
+        return;
+    }
+
+    if let Some(ending) = get_latest_ending(e) {
+        asr::timer::set_variable("latest ending", ending.name());
+    }
+}
+
+fn handle_relics(state: &mut AutoSplitterState, e: &Env) {
+    // only update relics if timer is running or paused
+    if is_timer_state_between_runs(asr::timer::state()) {
+        return;
+    }
+
+    // only update relics if game state is non-menu
+    let game_state: i32 = e.mem.deref(&e.gm.game_state).unwrap_or_default();
+    if !NON_MENU_GAME_STATES.contains(&game_state) {
+        return;
+    }
+
+    if let Some(relics) = state.store.get_relic_counts_pair() {
+        asr::timer::set_variable(
+            "relics",
+            &format!(
+                "{} collected, {} deposited",
+                relics.current.collected, relics.current.deposited
+            ),
+        );
+    }
+    if let Some(mementos) = state.store.get_mementos_count_pair() {
+        asr::timer::set_variable("mementos", &format!("{} deposited", mementos.current));
+    }
+}
+
+fn handle_currency(state: &mut AutoSplitterState, e: &Env, ss: &SceneStore) {
+    // only update currency if timer is running or paused
+    if is_timer_state_between_runs(asr::timer::state()) {
+        return;
+    }
+
+    // only update currency if game state is non-menu
+    let game_state: i32 = e.mem.deref(&e.gm.game_state).unwrap_or_default();
+    if !NON_MENU_GAME_STATES.contains(&game_state) {
+        return;
+    }
+
+    if let Some(rosaries) = rosaries_string(e) {
+        asr::timer::set_variable("rosaries", &rosaries);
+    }
+    if let Some(shards) = shell_shards_string(e) {
+        asr::timer::set_variable("shell shards", &shards);
+    }
+
+    // a drop in rosaries while alive is spending in the current scene
+    let Some(geo) = state.store.get_i32_pair_bang("geo", &get_geo, Some(e)) else {
+        return;
+    };
+    if geo.current < geo.old && e.mem.deref(&e.pd.health).is_ok_and(|h: i32| h > 0) {
+        let scene = ss.pair().current;
+        state.store.add_spent(scene, geo.old - geo.current);
+        asr::print_message(&format!(
+            "Spent {} rosaries in {}",
+            geo.old - geo.current,
+            scene
+        ));
+    }
 }
 
 // --------------------------------------------------------
@@ -1284,3 +1720,14 @@ fn settings_list<T: asr::settings::AsValue + Copy>(src: &[T]) -> asr::settings::
     }
     l
 }
+
+fn settings_get_list<T>(
+    m: &asr::settings::Map,
+    key: &str,
+    f: impl Fn(asr::settings::Value) -> Option<T>,
+) -> Vec<T> {
+    let Some(l) = m.get(key).and_then(|v| v.get_list()) else {
+        return Vec::new();
+    };
+    l.iter().filter_map(f).collect()
+}
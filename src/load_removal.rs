@@ -0,0 +1,684 @@
+use asr::{settings::Gui, time::Duration};
+
+use crate::{
+    silksong_memory::{
+        GAME_STATE_CUTSCENE, GAME_STATE_ENTERING_LEVEL, GAME_STATE_EXITING_LEVEL,
+        GAME_STATE_INACTIVE, GAME_STATE_LOADING, GAME_STATE_MAIN_MENU, GAME_STATE_PLAYING,
+        HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL, MENU_TITLE, OPENING_SCENES, QUIT_TO_MENU,
+        UI_STATE_CUTSCENE, UI_STATE_PAUSED, UI_STATE_PLAYING,
+    },
+    timer::TimeVariable,
+};
+
+/// The reasons load removal can pause game time, in the order they're checked
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseReason {
+    /// The game is closed, crashed, or still attaching
+    Detached,
+    /// Looking for the teleport after loading a save, or teleporting
+    Teleport,
+    /// Playing or entering a level, but the UI isn't playing
+    UiNotPlaying,
+    /// Not playing and not accepting input, outside of cutscenes
+    NotAcceptingInput,
+    /// Exiting a level, or loading
+    ExitingLevel,
+    /// The hero is waiting to enter the next level
+    HeroWaitingToEnter,
+    /// Loading while respawning from a hazard
+    HazardRespawnLoading,
+    /// Loading outside of a scene transition
+    Loading,
+    /// The current load's duration is still counting up
+    LoadDuration,
+    /// Loading into or out of the menu
+    LoadingMenu,
+    /// On the file select screen
+    FileSelect,
+}
+
+impl PauseReason {
+    pub const ALL: [PauseReason; 11] = [
+        PauseReason::Detached,
+        PauseReason::Teleport,
+        PauseReason::UiNotPlaying,
+        PauseReason::NotAcceptingInput,
+        PauseReason::ExitingLevel,
+        PauseReason::HeroWaitingToEnter,
+        PauseReason::HazardRespawnLoading,
+        PauseReason::Loading,
+        PauseReason::LoadDuration,
+        PauseReason::LoadingMenu,
+        PauseReason::FileSelect,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PauseReason::Detached => "detached",
+            PauseReason::Teleport => "teleport",
+            PauseReason::UiNotPlaying => "ui-not-playing",
+            PauseReason::NotAcceptingInput => "not-accepting-input",
+            PauseReason::ExitingLevel => "exiting-level",
+            PauseReason::HeroWaitingToEnter => "hero-waiting-to-enter",
+            PauseReason::HazardRespawnLoading => "hazard-respawn-loading",
+            PauseReason::Loading => "loading",
+            PauseReason::LoadDuration => "load-duration",
+            PauseReason::LoadingMenu => "loading-menu",
+            PauseReason::FileSelect => "file-select",
+        }
+    }
+
+    /// The name of the variable for the time accumulated with this reason
+    pub fn time_variable(self) -> &'static str {
+        match self {
+            PauseReason::Detached => "load time detached",
+            PauseReason::Teleport => "load time teleport",
+            PauseReason::UiNotPlaying => "load time ui-not-playing",
+            PauseReason::NotAcceptingInput => "load time not-accepting-input",
+            PauseReason::ExitingLevel => "load time exiting-level",
+            PauseReason::HeroWaitingToEnter => "load time hero-waiting-to-enter",
+            PauseReason::HazardRespawnLoading => "load time hazard-respawn-loading",
+            PauseReason::Loading => "load time loading",
+            PauseReason::LoadDuration => "load time load-duration",
+            PauseReason::LoadingMenu => "load time loading-menu",
+            PauseReason::FileSelect => "load time file-select",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+pub fn pause_reason_name(reason: Option<PauseReason>) -> &'static str {
+    reason.map_or("running", PauseReason::name)
+}
+
+/// Real time spent paused for each reason during a run
+pub struct PauseReasonTimes {
+    times: [Duration; PauseReason::ALL.len()],
+    variables: [TimeVariable; PauseReason::ALL.len()],
+}
+
+impl PauseReasonTimes {
+    pub fn new() -> PauseReasonTimes {
+        PauseReasonTimes {
+            times: [Duration::ZERO; PauseReason::ALL.len()],
+            variables: PauseReason::ALL.map(|r| TimeVariable::new(r.time_variable())),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.times = [Duration::ZERO; PauseReason::ALL.len()];
+        for v in self.variables.iter_mut() {
+            v.clear();
+        }
+    }
+
+    /// Adds the time paused for the reason, and shows the new total
+    pub fn add(&mut self, reason: PauseReason, time: Duration) {
+        let i = reason.index();
+        self.times[i] += time;
+        self.variables[i].set(self.times[i]);
+    }
+}
+
+impl Default for PauseReasonTimes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Timing profiles, each with its own rules for which clauses of load removal pause game time
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, PartialEq)]
+pub enum TimingProfile {
+    /// Standard IGT
+    #[default]
+    StandardIgt,
+    /// Glitched
+    Glitched,
+    /// RTA-no-loads
+    RtaNoLoads,
+}
+
+/// Which clauses of load removal are turned on
+pub struct PauseRules {
+    /// Pause on the file select screen
+    pub file_select: bool,
+    /// Pause while the pause menu is open
+    pub pause_menu: bool,
+    /// Keep game time running through transitions taken with the inventory open
+    pub inventory: bool,
+    /// Keep game time running through the opening cutscene's duplicate room load,
+    /// instead of pausing for it like any other load
+    pub opening_cutscene: bool,
+    /// Pause from loading a save until the hero is placed
+    pub teleport_window: bool,
+}
+
+impl TimingProfile {
+    pub fn pause_rules(self) -> PauseRules {
+        match self {
+            TimingProfile::StandardIgt => PauseRules {
+                file_select: true,
+                pause_menu: true,
+                inventory: true,
+                opening_cutscene: true,
+                teleport_window: true,
+            },
+            TimingProfile::Glitched => PauseRules {
+                file_select: false,
+                pause_menu: true,
+                inventory: true,
+                opening_cutscene: true,
+                teleport_window: true,
+            },
+            TimingProfile::RtaNoLoads => PauseRules {
+                file_select: false,
+                pause_menu: false,
+                inventory: false,
+                opening_cutscene: false,
+                teleport_window: false,
+            },
+        }
+    }
+}
+
+// --------------------------------------------------------
+
+/// What load removal reads from the game on one tick
+#[derive(Clone, Debug, Default)]
+pub struct LoadSignals<'a> {
+    pub game_state: i32,
+    pub ui_state: i32,
+    pub scene_name: &'a str,
+    pub next_scene: &'a str,
+    pub teleporting: bool,
+    pub hazard_respawning: bool,
+    pub accepting_input: bool,
+    pub hero_transition_state: i32,
+    pub scene_load_null: bool,
+    pub scene_load_activation_allowed: bool,
+    pub is_inventory_open: bool,
+    /// GameManager.isLoading, which with `!is_in_scene_transition` stands in for
+    /// Hollow Knight's `tile_map_dirty && !uses_scene_transition_routine`,
+    /// since Silksong's GameManager has neither
+    pub is_loading: bool,
+    /// GameManager.IsInSceneTransition
+    pub is_in_scene_transition: bool,
+    /// GameManager.currentLoadDuration, in seconds
+    pub current_load_duration: f32,
+    /// On the file select screen, with no black threaded save slots
+    pub file_select: bool,
+}
+
+/// What load removal remembers from one tick to the next
+pub struct LoadRemoval {
+    /// Whether game time was paused because the game was detached,
+    /// and should stay paused until the game is playing again
+    pub detached: bool,
+    look_for_teleporting: bool,
+    mms_room_dupe: bool,
+    last_game_state: i32,
+    last_load_duration: f32,
+}
+
+impl LoadRemoval {
+    pub fn new() -> LoadRemoval {
+        LoadRemoval {
+            detached: false,
+            look_for_teleporting: false,
+            mms_room_dupe: false,
+            last_game_state: GAME_STATE_INACTIVE,
+            last_load_duration: 0.0,
+        }
+    }
+
+    /// Starts over, on a reset
+    pub fn clear(&mut self) {
+        self.detached = false;
+        self.look_for_teleporting = false;
+        self.last_game_state = GAME_STATE_INACTIVE;
+        self.last_load_duration = 0.0;
+    }
+
+    pub fn last_game_state(&self) -> i32 {
+        self.last_game_state
+    }
+
+    pub fn mms_room_dupe(&self) -> bool {
+        self.mms_room_dupe
+    }
+
+    /// Updates on a tick while the game is detached, producing why game time should be paused.
+    /// When crash time counts, game time runs through the crash,
+    /// even if it was paused for a load when the game crashed
+    pub fn detach(&mut self, count_crash_time: bool) -> Option<PauseReason> {
+        self.detached = !count_crash_time;
+        self.detached.then_some(PauseReason::Detached)
+    }
+
+    /// Updates from one tick of signals, producing why game time should be paused, if it should
+    pub fn tick(&mut self, rules: &PauseRules, s: &LoadSignals) -> Option<PauseReason> {
+        let loading_menu = (s.scene_name == QUIT_TO_MENU)
+            || (s.scene_name != MENU_TITLE
+                && (s.next_scene.is_empty() || s.next_scene == MENU_TITLE));
+
+        if self.detached && s.game_state == GAME_STATE_PLAYING {
+            self.detached = false;
+        }
+
+        if s.game_state == GAME_STATE_PLAYING && self.last_game_state == GAME_STATE_MAIN_MENU {
+            self.look_for_teleporting = true;
+        }
+        if self.look_for_teleporting
+            && (s.teleporting
+                || (s.game_state != GAME_STATE_PLAYING
+                    && s.game_state != GAME_STATE_ENTERING_LEVEL))
+        {
+            self.look_for_teleporting = false;
+        }
+
+        if s.game_state == GAME_STATE_LOADING
+            && self.last_game_state == GAME_STATE_CUTSCENE
+            && OPENING_SCENES.contains(&s.scene_name)
+        {
+            self.mms_room_dupe = true;
+        } else if s.game_state == GAME_STATE_PLAYING {
+            self.mms_room_dupe = false;
+        }
+        self.last_game_state = s.game_state;
+
+        let load_duration_counting = s.current_load_duration > self.last_load_duration;
+        self.last_load_duration = s.current_load_duration;
+
+        let mms_room_dupe = rules.opening_cutscene && self.mms_room_dupe;
+        let inventory_open_transition = rules.inventory && s.is_inventory_open;
+
+        if self.detached {
+            Some(PauseReason::Detached)
+        } else if (rules.teleport_window && self.look_for_teleporting)
+            || (s.teleporting && !s.hazard_respawning)
+        {
+            Some(PauseReason::Teleport)
+        } else if (s.game_state == GAME_STATE_PLAYING || s.game_state == GAME_STATE_ENTERING_LEVEL)
+            && s.ui_state != UI_STATE_PLAYING
+            && (rules.pause_menu || s.ui_state != UI_STATE_PAUSED)
+        {
+            Some(PauseReason::UiNotPlaying)
+        } else if s.game_state != GAME_STATE_PLAYING
+            && s.game_state != GAME_STATE_CUTSCENE
+            && s.ui_state != UI_STATE_CUTSCENE
+            && !s.accepting_input
+            && !mms_room_dupe
+        {
+            Some(PauseReason::NotAcceptingInput)
+        } else if (s.game_state == GAME_STATE_EXITING_LEVEL
+            && s.ui_state != UI_STATE_CUTSCENE
+            && (s.scene_load_null || s.scene_load_activation_allowed)
+            && !inventory_open_transition
+            && !mms_room_dupe)
+            || s.game_state == GAME_STATE_LOADING
+        {
+            Some(PauseReason::ExitingLevel)
+        } else if s.hero_transition_state == HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL
+            && !inventory_open_transition
+        {
+            Some(PauseReason::HeroWaitingToEnter)
+        } else if s.hazard_respawning && s.is_loading {
+            Some(PauseReason::HazardRespawnLoading)
+        } else if s.is_loading && !s.is_in_scene_transition && !mms_room_dupe {
+            Some(PauseReason::Loading)
+        } else if load_duration_counting && !mms_room_dupe {
+            Some(PauseReason::LoadDuration)
+        } else if s.ui_state != UI_STATE_PLAYING
+            && (loading_menu
+                || (s.ui_state != UI_STATE_PAUSED
+                    && s.ui_state != UI_STATE_CUTSCENE
+                    && (!s.next_scene.is_empty())))
+            && s.next_scene != s.scene_name
+        {
+            Some(PauseReason::LoadingMenu)
+        } else if rules.file_select && s.file_select {
+            Some(PauseReason::FileSelect)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for LoadRemoval {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+    use crate::silksong_memory::UI_STATE_MAIN_MENU;
+
+    fn playing(scene: &'static str) -> LoadSignals<'static> {
+        LoadSignals {
+            game_state: GAME_STATE_PLAYING,
+            ui_state: UI_STATE_PLAYING,
+            scene_name: scene,
+            next_scene: scene,
+            accepting_input: true,
+            ..Default::default()
+        }
+    }
+
+    fn file_select() -> LoadSignals<'static> {
+        LoadSignals {
+            game_state: GAME_STATE_MAIN_MENU,
+            ui_state: UI_STATE_MAIN_MENU,
+            file_select: true,
+            ..playing(MENU_TITLE)
+        }
+    }
+
+    /// Replays a trace of signals from a fresh start, producing the pause reason on each tick
+    fn replay(profile: TimingProfile, trace: &[LoadSignals]) -> Vec<Option<PauseReason>> {
+        let rules = profile.pause_rules();
+        let mut load_removal = LoadRemoval::new();
+        trace.iter().map(|s| load_removal.tick(&rules, s)).collect()
+    }
+
+    fn paused(profile: TimingProfile, trace: &[LoadSignals]) -> Vec<bool> {
+        replay(profile, trace).iter().map(Option::is_some).collect()
+    }
+
+    #[test]
+    fn walking_transition_pauses_from_exit_until_the_hero_enters() {
+        let trace = [
+            playing("Bone_01"),
+            LoadSignals {
+                game_state: GAME_STATE_EXITING_LEVEL,
+                next_scene: "Bone_02",
+                accepting_input: false,
+                scene_load_activation_allowed: true,
+                ..playing("Bone_01")
+            },
+            LoadSignals {
+                game_state: GAME_STATE_LOADING,
+                next_scene: "Bone_02",
+                accepting_input: false,
+                ..playing("Bone_01")
+            },
+            LoadSignals {
+                game_state: GAME_STATE_ENTERING_LEVEL,
+                accepting_input: false,
+                ..playing("Bone_02")
+            },
+            LoadSignals {
+                hero_transition_state: HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL,
+                ..playing("Bone_02")
+            },
+            playing("Bone_02"),
+        ];
+        assert_eq!(
+            paused(TimingProfile::StandardIgt, &trace),
+            vec![false, true, true, true, true, false]
+        );
+        assert_eq!(
+            replay(TimingProfile::StandardIgt, &trace)[4],
+            Some(PauseReason::HeroWaitingToEnter)
+        );
+    }
+
+    #[test]
+    fn teleporting_pauses() {
+        let trace = [
+            playing("Bellway_01"),
+            LoadSignals {
+                teleporting: true,
+                ..playing("Bellway_01")
+            },
+            playing("Bellway_02"),
+        ];
+        assert_eq!(
+            replay(TimingProfile::StandardIgt, &trace),
+            vec![None, Some(PauseReason::Teleport), None]
+        );
+    }
+
+    #[test]
+    fn hazard_respawns_never_pause() {
+        let hazard_respawn = LoadSignals {
+            teleporting: true,
+            hazard_respawning: true,
+            accepting_input: false,
+            ..playing("Bone_05")
+        };
+        let trace = [playing("Bone_05"), hazard_respawn.clone(), hazard_respawn];
+        for profile in [
+            TimingProfile::StandardIgt,
+            TimingProfile::Glitched,
+            TimingProfile::RtaNoLoads,
+        ] {
+            assert_eq!(paused(profile, &trace), vec![false, false, false]);
+        }
+    }
+
+    #[test]
+    fn loading_a_save_pauses_until_the_teleport_ends() {
+        let trace = [
+            file_select(),
+            playing("Tut_01"),
+            LoadSignals {
+                teleporting: true,
+                ..playing("Tut_01")
+            },
+            playing("Tut_01"),
+        ];
+        assert_eq!(
+            replay(TimingProfile::StandardIgt, &trace),
+            vec![
+                Some(PauseReason::FileSelect),
+                Some(PauseReason::Teleport),
+                Some(PauseReason::Teleport),
+                None
+            ]
+        );
+        // without the teleport window, only the teleport itself pauses
+        assert_eq!(
+            paused(TimingProfile::RtaNoLoads, &trace),
+            vec![false, false, true, false]
+        );
+    }
+
+    #[test]
+    fn pause_menu_depends_on_the_profile() {
+        let trace = [
+            playing("Bone_01"),
+            LoadSignals {
+                ui_state: UI_STATE_PAUSED,
+                ..playing("Bone_01")
+            },
+            playing("Bone_01"),
+        ];
+        assert_eq!(
+            paused(TimingProfile::StandardIgt, &trace),
+            vec![false, true, false]
+        );
+        assert_eq!(
+            paused(TimingProfile::RtaNoLoads, &trace),
+            vec![false, false, false]
+        );
+    }
+
+    #[test]
+    fn file_select_depends_on_the_profile() {
+        let trace = [file_select()];
+        assert_eq!(paused(TimingProfile::StandardIgt, &trace), vec![true]);
+        assert_eq!(paused(TimingProfile::Glitched, &trace), vec![false]);
+        assert_eq!(paused(TimingProfile::RtaNoLoads, &trace), vec![false]);
+    }
+
+    #[test]
+    fn opening_cutscene_room_dupe_depends_on_the_profile() {
+        let opening = OPENING_SCENES[0];
+        let trace = [
+            LoadSignals {
+                game_state: GAME_STATE_CUTSCENE,
+                ui_state: UI_STATE_CUTSCENE,
+                ..playing(opening)
+            },
+            LoadSignals {
+                game_state: GAME_STATE_LOADING,
+                accepting_input: false,
+                ..playing(opening)
+            },
+            LoadSignals {
+                game_state: GAME_STATE_ENTERING_LEVEL,
+                accepting_input: false,
+                ..playing(opening)
+            },
+        ];
+        assert_eq!(
+            paused(TimingProfile::StandardIgt, &trace),
+            vec![false, true, false]
+        );
+        assert_eq!(
+            paused(TimingProfile::RtaNoLoads, &trace),
+            vec![false, true, true]
+        );
+    }
+
+    #[test]
+    fn detached_stays_paused_until_playing() {
+        let rules = TimingProfile::StandardIgt.pause_rules();
+        let mut load_removal = LoadRemoval::new();
+        load_removal.detached = true;
+        let trace = [
+            file_select(),
+            LoadSignals {
+                game_state: GAME_STATE_LOADING,
+                accepting_input: false,
+                ..playing("Bone_01")
+            },
+            playing("Bone_01"),
+        ];
+        let reasons: Vec<_> = trace.iter().map(|s| load_removal.tick(&rules, s)).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                Some(PauseReason::Detached),
+                Some(PauseReason::Detached),
+                None
+            ]
+        );
+        assert!(!load_removal.detached);
+    }
+
+    #[test]
+    fn hazard_respawn_pauses_only_while_loading() {
+        let hazard_respawn = LoadSignals {
+            teleporting: true,
+            hazard_respawning: true,
+            ..playing("Bone_05")
+        };
+        let trace = [
+            playing("Bone_05"),
+            hazard_respawn.clone(),
+            LoadSignals {
+                is_loading: true,
+                is_in_scene_transition: true,
+                ..hazard_respawn.clone()
+            },
+            hazard_respawn,
+            playing("Bone_05"),
+        ];
+        assert_eq!(
+            replay(TimingProfile::StandardIgt, &trace),
+            vec![
+                None,
+                None,
+                Some(PauseReason::HazardRespawnLoading),
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn loading_outside_a_scene_transition_pauses() {
+        let trace = [
+            playing("Bone_01"),
+            LoadSignals {
+                is_loading: true,
+                ..playing("Bone_01")
+            },
+            LoadSignals {
+                is_loading: true,
+                is_in_scene_transition: true,
+                ..playing("Bone_01")
+            },
+            playing("Bone_01"),
+        ];
+        assert_eq!(
+            replay(TimingProfile::StandardIgt, &trace),
+            vec![None, Some(PauseReason::Loading), None, None]
+        );
+    }
+
+    #[test]
+    fn counting_load_duration_pauses() {
+        let trace = [
+            playing("Bone_01"),
+            LoadSignals {
+                current_load_duration: 0.5,
+                ..playing("Bone_01")
+            },
+            LoadSignals {
+                current_load_duration: 1.0,
+                ..playing("Bone_01")
+            },
+            // the duration of a finished load stays until the next load
+            LoadSignals {
+                current_load_duration: 1.0,
+                ..playing("Bone_01")
+            },
+        ];
+        assert_eq!(
+            replay(TimingProfile::StandardIgt, &trace),
+            vec![
+                None,
+                Some(PauseReason::LoadDuration),
+                Some(PauseReason::LoadDuration),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn crash_while_paused_runs_when_crash_time_counts() {
+        let rules = TimingProfile::StandardIgt.pause_rules();
+        let loading = LoadSignals {
+            game_state: GAME_STATE_LOADING,
+            accepting_input: false,
+            ..playing("Bone_01")
+        };
+        let mut counted = LoadRemoval::new();
+        assert_eq!(
+            counted.tick(&rules, &loading),
+            Some(PauseReason::ExitingLevel)
+        );
+        assert_eq!(counted.detach(true), None);
+        assert_eq!(counted.tick(&rules, &playing("Bone_01")), None);
+
+        let mut not_counted = LoadRemoval::new();
+        assert_eq!(
+            not_counted.tick(&rules, &loading),
+            Some(PauseReason::ExitingLevel)
+        );
+        assert_eq!(not_counted.detach(false), Some(PauseReason::Detached));
+        assert_eq!(
+            not_counted.tick(&rules, &file_select()),
+            Some(PauseReason::Detached)
+        );
+    }
+}
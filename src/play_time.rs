@@ -0,0 +1,103 @@
+use asr::settings::Gui;
+
+/// Where game time comes from
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, PartialEq)]
+pub enum GameTimeSource {
+    /// Load Removal
+    #[default]
+    LoadRemoval,
+    /// In-game Play Time
+    PlayTime,
+}
+
+/// Game time accumulated from the game's play time counter.
+///
+/// On save-quit and reload, play time jumps back to the value in the save,
+/// so the clock loses track in the menu, and a jump backward starts a new baseline,
+/// and the time since the last save still counts.
+pub struct PlayTimeClock {
+    total: f64,
+    last: Option<f32>,
+}
+
+impl PlayTimeClock {
+    pub fn new() -> PlayTimeClock {
+        PlayTimeClock {
+            total: 0.0,
+            last: None,
+        }
+    }
+
+    /// Starts over from zero, on a reset
+    pub fn clear(&mut self) {
+        self.total = 0.0;
+        self.last = None;
+    }
+
+    /// Continues from a total restored from a checkpoint
+    pub fn restore(&mut self, total: f64) {
+        self.total = total;
+        self.last = None;
+    }
+
+    pub fn total(&self) -> f64 {
+        self.total
+    }
+
+    /// Forgets the last play time, such as in the menu,
+    /// so that the next play time read starts a new baseline
+    pub fn lose_track(&mut self) {
+        self.last = None;
+    }
+
+    /// Accumulates the step from the last play time, producing the new total in seconds.
+    /// A step backward is from loading a save, so it only starts a new baseline
+    pub fn update(&mut self, play_time: f32) -> f64 {
+        if let Some(last) = self.last {
+            if last <= play_time {
+                self.total += (play_time - last) as f64;
+            }
+        }
+        self.last = Some(play_time);
+        self.total
+    }
+}
+
+impl Default for PlayTimeClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_steps_forward() {
+        let mut clock = PlayTimeClock::new();
+        clock.update(100.0);
+        clock.update(101.5);
+        assert_eq!(clock.update(104.0), 4.0);
+    }
+
+    #[test]
+    fn reloading_a_save_keeps_the_time_since_the_last_save() {
+        let mut clock = PlayTimeClock::new();
+        clock.update(100.0);
+        clock.update(160.0);
+        // save-quit through the menu, then reload the save from play time 120
+        clock.lose_track();
+        clock.update(120.0);
+        assert_eq!(clock.update(130.0), 70.0);
+    }
+
+    #[test]
+    fn a_jump_backward_starts_a_new_baseline() {
+        let mut clock = PlayTimeClock::new();
+        clock.update(100.0);
+        clock.update(160.0);
+        clock.update(120.0);
+        assert_eq!(clock.update(125.0), 65.0);
+    }
+}
@@ -0,0 +1,683 @@
+/// Real Silksong scene names, sorted, with the map zone of each scene
+/// for scenes that are all in one map zone, named like `GlobalEnums.MapZone`.
+/// Scenes such as `Coral_` ones are split between several map zones,
+/// so those are left to the game's map zone
+static SCENES: [(&str, Option<&str>); 630] = [
+    ("Abyss_01", Some("ABYSS")),
+    ("Abyss_02", Some("ABYSS")),
+    ("Abyss_02b", Some("ABYSS")),
+    ("Abyss_03", Some("ABYSS")),
+    ("Abyss_04", Some("ABYSS")),
+    ("Abyss_05", Some("ABYSS")),
+    ("Abyss_06", Some("ABYSS")),
+    ("Abyss_07", Some("ABYSS")),
+    ("Abyss_08", Some("ABYSS")),
+    ("Abyss_09", Some("ABYSS")),
+    ("Abyss_10", Some("ABYSS")),
+    ("Abyss_11", Some("ABYSS")),
+    ("Abyss_12", Some("ABYSS")),
+    ("Abyss_13", Some("ABYSS")),
+    ("Abyss_Cocoon", Some("ABYSS")),
+    ("Ant_01", Some("HUNTERS_NEST")),
+    ("Ant_02", Some("HUNTERS_NEST")),
+    ("Ant_03", Some("HUNTERS_NEST")),
+    ("Ant_04", Some("HUNTERS_NEST")),
+    ("Ant_04_left", Some("HUNTERS_NEST")),
+    ("Ant_04_mid", Some("HUNTERS_NEST")),
+    ("Ant_05", Some("HUNTERS_NEST")),
+    ("Ant_05b", Some("HUNTERS_NEST")),
+    ("Ant_05c", Some("HUNTERS_NEST")),
+    ("Ant_06", Some("HUNTERS_NEST")),
+    ("Ant_07", Some("HUNTERS_NEST")),
+    ("Ant_08", Some("HUNTERS_NEST")),
+    ("Ant_09", Some("HUNTERS_NEST")),
+    ("Ant_10", Some("HUNTERS_NEST")),
+    ("Ant_11", Some("HUNTERS_NEST")),
+    ("Ant_12", Some("HUNTERS_NEST")),
+    ("Ant_13", Some("HUNTERS_NEST")),
+    ("Ant_14", Some("HUNTERS_NEST")),
+    ("Ant_15", Some("HUNTERS_NEST")),
+    ("Ant_16", Some("HUNTERS_NEST")),
+    ("Ant_17", Some("HUNTERS_NEST")),
+    ("Ant_18", Some("HUNTERS_NEST")),
+    ("Ant_19", Some("HUNTERS_NEST")),
+    ("Ant_20", Some("HUNTERS_NEST")),
+    ("Ant_21", Some("HUNTERS_NEST")),
+    ("Ant_Merchant", Some("HUNTERS_NEST")),
+    ("Ant_Queen", Some("HUNTERS_NEST")),
+    ("Aqueduct_01", Some("AQUEDUCT")),
+    ("Aqueduct_02", Some("AQUEDUCT")),
+    ("Aqueduct_03", Some("AQUEDUCT")),
+    ("Aqueduct_04", Some("AQUEDUCT")),
+    ("Aqueduct_05", Some("AQUEDUCT")),
+    ("Aqueduct_06", Some("AQUEDUCT")),
+    ("Aqueduct_07", Some("AQUEDUCT")),
+    ("Aqueduct_08", Some("AQUEDUCT")),
+    ("Arborium_01", Some("ARBORIUM")),
+    ("Arborium_02", Some("ARBORIUM")),
+    ("Arborium_03", Some("ARBORIUM")),
+    ("Arborium_04", Some("ARBORIUM")),
+    ("Arborium_05", Some("ARBORIUM")),
+    ("Arborium_06", Some("ARBORIUM")),
+    ("Arborium_07", Some("ARBORIUM")),
+    ("Arborium_08", Some("ARBORIUM")),
+    ("Arborium_09", Some("ARBORIUM")),
+    ("Arborium_10", Some("ARBORIUM")),
+    ("Arborium_11", Some("ARBORIUM")),
+    ("Arborium_Tube", Some("ARBORIUM")),
+    ("Aspid_01", None),
+    ("Bellshrine", None),
+    ("Bellshrine_02", None),
+    ("Bellshrine_03", None),
+    ("Bellshrine_05", None),
+    ("Bellshrine_Coral", None),
+    ("Bellshrine_Enclave", None),
+    ("Belltown", Some("BELLTOWN")),
+    ("Belltown_04", Some("BELLTOWN")),
+    ("Belltown_06", Some("BELLTOWN")),
+    ("Belltown_07", Some("BELLTOWN")),
+    ("Belltown_08", Some("BELLTOWN")),
+    ("Belltown_Room_Fisher", Some("BELLTOWN")),
+    ("Belltown_Room_Relic", Some("BELLTOWN")),
+    ("Belltown_Room_Spare", Some("BELLTOWN")),
+    ("Belltown_Room_doctor", Some("BELLTOWN")),
+    ("Belltown_Room_pinsmith", Some("BELLTOWN")),
+    ("Belltown_Room_shellwood", Some("BELLTOWN")),
+    ("Belltown_Shrine", Some("BELLTOWN")),
+    ("Belltown_basement", Some("BELLTOWN")),
+    ("Belltown_basement_03", Some("BELLTOWN")),
+    ("Bellway_01", None),
+    ("Bellway_02", None),
+    ("Bellway_03", None),
+    ("Bellway_04", None),
+    ("Bellway_05", None),
+    ("Bellway_06", None),
+    ("Bellway_07", None),
+    ("Bellway_08", None),
+    ("Bellway_Aqueduct", None),
+    ("Bellway_Centipede_Arena", None),
+    ("Bellway_City", None),
+    ("Bellway_Peak", None),
+    ("Bellway_Peak_Up", None),
+    ("Bellway_Shadow", None),
+    ("Bone_01", Some("PATH_OF_BONE")),
+    ("Bone_01b", Some("PATH_OF_BONE")),
+    ("Bone_01c", Some("PATH_OF_BONE")),
+    ("Bone_02", Some("PATH_OF_BONE")),
+    ("Bone_03", Some("PATH_OF_BONE")),
+    ("Bone_04", Some("PATH_OF_BONE")),
+    ("Bone_05", Some("PATH_OF_BONE")),
+    ("Bone_05b", Some("PATH_OF_BONE")),
+    ("Bone_06", Some("PATH_OF_BONE")),
+    ("Bone_07", Some("PATH_OF_BONE")),
+    ("Bone_08", Some("PATH_OF_BONE")),
+    ("Bone_09", Some("PATH_OF_BONE")),
+    ("Bone_10", Some("PATH_OF_BONE")),
+    ("Bone_11", Some("PATH_OF_BONE")),
+    ("Bone_11b", Some("PATH_OF_BONE")),
+    ("Bone_12", Some("PATH_OF_BONE")),
+    ("Bone_13", Some("PATH_OF_BONE")),
+    ("Bone_14", Some("PATH_OF_BONE")),
+    ("Bone_15", Some("PATH_OF_BONE")),
+    ("Bone_16", Some("PATH_OF_BONE")),
+    ("Bone_17", Some("PATH_OF_BONE")),
+    ("Bone_18", Some("PATH_OF_BONE")),
+    ("Bone_19", Some("PATH_OF_BONE")),
+    ("Bone_20", Some("PATH_OF_BONE")),
+    ("Bone_East_01", Some("WILDS")),
+    ("Bone_East_02", Some("WILDS")),
+    ("Bone_East_03", Some("WILDS")),
+    ("Bone_East_04", Some("WILDS")),
+    ("Bone_East_04b", Some("WILDS")),
+    ("Bone_East_04c", Some("WILDS")),
+    ("Bone_East_05", Some("WILDS")),
+    ("Bone_East_06", Some("WILDS")),
+    ("Bone_East_07", Some("WILDS")),
+    ("Bone_East_08", Some("WILDS")),
+    ("Bone_East_09", Some("WILDS")),
+    ("Bone_East_09b", Some("WILDS")),
+    ("Bone_East_10", Some("WILDS")),
+    ("Bone_East_10_Church", Some("WILDS")),
+    ("Bone_East_10_Room", Some("WILDS")),
+    ("Bone_East_11", Some("WILDS")),
+    ("Bone_East_12", Some("WILDS")),
+    ("Bone_East_13", Some("WILDS")),
+    ("Bone_East_14", Some("WILDS")),
+    ("Bone_East_14b", Some("WILDS")),
+    ("Bone_East_15", Some("WILDS")),
+    ("Bone_East_16", Some("WILDS")),
+    ("Bone_East_17", Some("WILDS")),
+    ("Bone_East_17b", Some("WILDS")),
+    ("Bone_East_18", Some("WILDS")),
+    ("Bone_East_18b", Some("WILDS")),
+    ("Bone_East_18c", Some("WILDS")),
+    ("Bone_East_19", Some("WILDS")),
+    ("Bone_East_20", Some("WILDS")),
+    ("Bone_East_21", Some("WILDS")),
+    ("Bone_East_22", Some("WILDS")),
+    ("Bone_East_23", Some("WILDS")),
+    ("Bone_East_24", Some("WILDS")),
+    ("Bone_East_25", Some("WILDS")),
+    ("Bone_East_26", Some("WILDS")),
+    ("Bone_East_27", Some("WILDS")),
+    ("Bone_East_LavaChallenge", Some("WILDS")),
+    ("Bone_East_Umbrella", Some("WILDS")),
+    ("Bone_East_Weavehome", Some("WILDS")),
+    ("Bonegrave", Some("BONETOWN")),
+    ("Bonetown", Some("BONETOWN")),
+    ("Cinematic_Ending_A", None),
+    ("Cinematic_Ending_B", None),
+    ("Cinematic_Ending_C", None),
+    ("Cinematic_Ending_D", None),
+    ("Cinematic_Ending_E", None),
+    ("Cinematic_Stag_travel", None),
+    ("Clover_01", Some("CLOVER")),
+    ("Clover_01b", Some("CLOVER")),
+    ("Clover_02", Some("CLOVER")),
+    ("Clover_02c", Some("CLOVER")),
+    ("Clover_03", Some("CLOVER")),
+    ("Clover_04", Some("CLOVER")),
+    ("Clover_04b", Some("CLOVER")),
+    ("Clover_05", Some("CLOVER")),
+    ("Clover_05b", Some("CLOVER")),
+    ("Clover_05c", Some("CLOVER")),
+    ("Clover_06", Some("CLOVER")),
+    ("Clover_07", Some("CLOVER")),
+    ("Clover_08", Some("CLOVER")),
+    ("Clover_09", Some("CLOVER")),
+    ("Clover_10", Some("CLOVER")),
+    ("Clover_11", Some("CLOVER")),
+    ("Clover_12", Some("CLOVER")),
+    ("Clover_13", Some("CLOVER")),
+    ("Clover_14", Some("CLOVER")),
+    ("Clover_15", Some("CLOVER")),
+    ("Clover_16", Some("CLOVER")),
+    ("Clover_17", Some("CLOVER")),
+    ("Clover_18", Some("CLOVER")),
+    ("Clover_19", Some("CLOVER")),
+    ("Clover_20", Some("CLOVER")),
+    ("Clover_21", Some("CLOVER")),
+    ("Cog_01", Some("COG_CORE")),
+    ("Cog_02", Some("COG_CORE")),
+    ("Cog_03", Some("COG_CORE")),
+    ("Cog_04", Some("COG_CORE")),
+    ("Cog_05", Some("COG_CORE")),
+    ("Cog_06", Some("COG_CORE")),
+    ("Cog_07", Some("COG_CORE")),
+    ("Cog_08", Some("COG_CORE")),
+    ("Cog_09", Some("COG_CORE")),
+    ("Cog_09_Destroyed", Some("COG_CORE")),
+    ("Cog_10", Some("COG_CORE")),
+    ("Cog_10_Destroyed", Some("COG_CORE")),
+    ("Cog_11", Some("COG_CORE")),
+    ("Cog_11_Route", Some("COG_CORE")),
+    ("Cog_Bench", Some("COG_CORE")),
+    ("Cog_Dancers", Some("COG_CORE")),
+    ("Cog_Dancers_boss", Some("COG_CORE")),
+    ("Cog_Pass", Some("COG_CORE")),
+    ("Coral_01", None),
+    ("Coral_02", None),
+    ("Coral_03", None),
+    ("Coral_04", None),
+    ("Coral_05", None),
+    ("Coral_06", None),
+    ("Coral_07", None),
+    ("Coral_08", None),
+    ("Coral_09", None),
+    ("Coral_10", None),
+    ("Coral_11", None),
+    ("Coral_11b", None),
+    ("Coral_12", None),
+    ("Coral_13", None),
+    ("Coral_14", None),
+    ("Coral_15", None),
+    ("Coral_16", None),
+    ("Coral_17", None),
+    ("Coral_18", None),
+    ("Coral_19", None),
+    ("Coral_19b", None),
+    ("Coral_20", None),
+    ("Coral_21", None),
+    ("Coral_22", None),
+    ("Coral_23", None),
+    ("Coral_24", None),
+    ("Coral_25", None),
+    ("Coral_26", None),
+    ("Coral_27", None),
+    ("Coral_28", None),
+    ("Coral_29", None),
+    ("Coral_30", None),
+    ("Coral_31", None),
+    ("Coral_32", None),
+    ("Coral_33", None),
+    ("Coral_34", None),
+    ("Coral_35", None),
+    ("Coral_35b", None),
+    ("Coral_36", None),
+    ("Coral_37", None),
+    ("Coral_38", None),
+    ("Coral_39", None),
+    ("Coral_40", None),
+    ("Coral_41", None),
+    ("Coral_42", None),
+    ("Coral_43", None),
+    ("Coral_44", None),
+    ("Coral_Judge_Arena", None),
+    ("Coral_Tower_01", None),
+    ("Cradle_01", Some("CRADLE")),
+    ("Cradle_01_Destroyed", Some("CRADLE")),
+    ("Cradle_02", Some("CRADLE")),
+    ("Cradle_02b", Some("CRADLE")),
+    ("Cradle_03", Some("CRADLE")),
+    ("Crawl_01", Some("CRAWLSPACE")),
+    ("Crawl_02", Some("CRAWLSPACE")),
+    ("Crawl_03", Some("CRAWLSPACE")),
+    ("Crawl_03b", Some("CRAWLSPACE")),
+    ("Crawl_04", Some("CRAWLSPACE")),
+    ("Crawl_05", Some("CRAWLSPACE")),
+    ("Crawl_06", Some("CRAWLSPACE")),
+    ("Crawl_07", Some("CRAWLSPACE")),
+    ("Crawl_08", Some("CRAWLSPACE")),
+    ("Crawl_09", Some("CRAWLSPACE")),
+    ("Crawl_10", Some("CRAWLSPACE")),
+    ("Demo Start", None),
+    ("Dock_01", Some("DOCKS")),
+    ("Dock_02", Some("DOCKS")),
+    ("Dock_02b", Some("DOCKS")),
+    ("Dock_03", Some("DOCKS")),
+    ("Dock_03b", Some("DOCKS")),
+    ("Dock_03c", Some("DOCKS")),
+    ("Dock_03d", Some("DOCKS")),
+    ("Dock_04", Some("DOCKS")),
+    ("Dock_05", Some("DOCKS")),
+    ("Dock_06", Some("DOCKS")),
+    ("Dock_06_Church", Some("DOCKS")),
+    ("Dock_07", Some("DOCKS")),
+    ("Dock_08", Some("DOCKS")),
+    ("Dock_09", Some("DOCKS")),
+    ("Dock_10", Some("DOCKS")),
+    ("Dock_11", Some("DOCKS")),
+    ("Dock_12", Some("DOCKS")),
+    ("Dock_13", Some("DOCKS")),
+    ("Dock_14", Some("DOCKS")),
+    ("Dock_15", Some("DOCKS")),
+    ("Dock_16", Some("DOCKS")),
+    ("Dust_01", Some("DUSTPENS")),
+    ("Dust_02", Some("DUSTPENS")),
+    ("Dust_03", Some("DUSTPENS")),
+    ("Dust_04", Some("DUSTPENS")),
+    ("Dust_05", Some("DUSTPENS")),
+    ("Dust_06", Some("DUSTPENS")),
+    ("Dust_07", Some("DUSTPENS")),
+    ("Dust_08", Some("DUSTPENS")),
+    ("Dust_09", Some("DUSTPENS")),
+    ("Dust_10", Some("DUSTPENS")),
+    ("Dust_11", Some("DUSTPENS")),
+    ("Dust_12", Some("DUSTPENS")),
+    ("Dust_Barb", Some("DUSTPENS")),
+    ("Dust_Chef", Some("DUSTPENS")),
+    ("Dust_Maze_01", Some("DUST_MAZE")),
+    ("Dust_Maze_02", Some("DUST_MAZE")),
+    ("Dust_Maze_03", Some("DUST_MAZE")),
+    ("Dust_Maze_04", Some("DUST_MAZE")),
+    ("Dust_Maze_05", Some("DUST_MAZE")),
+    ("Dust_Maze_06", Some("DUST_MAZE")),
+    ("Dust_Maze_07", Some("DUST_MAZE")),
+    ("Dust_Maze_08", Some("DUST_MAZE")),
+    ("Dust_Maze_08_completed", Some("DUST_MAZE")),
+    ("Dust_Maze_09", Some("DUST_MAZE")),
+    ("Dust_Maze_09_entrance", Some("DUST_MAZE")),
+    ("Dust_Maze_Last_Hall", Some("DUST_MAZE")),
+    ("Dust_Maze_crossing", Some("DUST_MAZE")),
+    ("Greymoor", Some("GREYMOOR")),
+    ("Greymoor_01", Some("GREYMOOR")),
+    ("Greymoor_02", Some("GREYMOOR")),
+    ("Greymoor_03", Some("GREYMOOR")),
+    ("Greymoor_04", Some("GREYMOOR")),
+    ("Greymoor_05", Some("GREYMOOR")),
+    ("Greymoor_06", Some("GREYMOOR")),
+    ("Greymoor_07", Some("GREYMOOR")),
+    ("Greymoor_08", Some("GREYMOOR")),
+    ("Greymoor_09", Some("GREYMOOR")),
+    ("Greymoor_10", Some("GREYMOOR")),
+    ("Greymoor_11", Some("GREYMOOR")),
+    ("Greymoor_12", Some("GREYMOOR")),
+    ("Greymoor_13", Some("GREYMOOR")),
+    ("Greymoor_14", Some("GREYMOOR")),
+    ("Greymoor_15", Some("GREYMOOR")),
+    ("Greymoor_15b", Some("GREYMOOR")),
+    ("Greymoor_16", Some("GREYMOOR")),
+    ("Greymoor_17", Some("GREYMOOR")),
+    ("Greymoor_18", Some("GREYMOOR")),
+    ("Greymoor_19", Some("GREYMOOR")),
+    ("Greymoor_20", Some("GREYMOOR")),
+    ("Greymoor_21", Some("GREYMOOR")),
+    ("Greymoor_22", Some("GREYMOOR")),
+    ("Greymoor_23", Some("GREYMOOR")),
+    ("Greymoor_24", Some("GREYMOOR")),
+    ("Halfway_01", Some("GREYMOOR")),
+    ("Hang_01", Some("HANG")),
+    ("Hang_02", Some("HANG")),
+    ("Hang_03", Some("HANG")),
+    ("Hang_04", Some("HANG")),
+    ("Hang_05", Some("HANG")),
+    ("Hang_06", Some("HANG")),
+    ("Hang_07", Some("HANG")),
+    ("Hang_08", Some("HANG")),
+    ("Hang_09", Some("HANG")),
+    ("Hang_10", Some("HANG")),
+    ("Hang_11", Some("HANG")),
+    ("Hang_12", Some("HANG")),
+    ("Hang_13", Some("HANG")),
+    ("Hang_14", Some("HANG")),
+    ("Hang_15", Some("HANG")),
+    ("Hang_16", Some("HANG")),
+    ("Hang_17", Some("HANG")),
+    ("Hang_17b", Some("HANG")),
+    ("Last_Dive", None),
+    ("Library_01", Some("LIBRARY")),
+    ("Library_02", Some("LIBRARY")),
+    ("Library_03", Some("LIBRARY")),
+    ("Library_04", Some("LIBRARY")),
+    ("Library_05", Some("LIBRARY")),
+    ("Library_06", Some("LIBRARY")),
+    ("Library_07", Some("LIBRARY")),
+    ("Library_08", Some("LIBRARY")),
+    ("Library_09", Some("LIBRARY")),
+    ("Library_09b", Some("LIBRARY")),
+    ("Library_10", Some("LIBRARY")),
+    ("Library_11", Some("LIBRARY")),
+    ("Library_12", Some("LIBRARY")),
+    ("Library_13", Some("LIBRARY")),
+    ("Library_14", Some("LIBRARY")),
+    ("Library_15", Some("LIBRARY")),
+    ("Memory_Ant_Queen", None),
+    ("Memory_Coral_Tower", None),
+    ("Memory_First_Sinner", None),
+    ("Memory_Needolin", None),
+    ("Memory_Red", None),
+    ("Memory_Silk_Heart_BellBeast", None),
+    ("Memory_Silk_Heart_LaceTower", None),
+    ("Memory_Silk_Heart_WardBoss", None),
+    ("Menu_Title", None),
+    ("Mosstown_01", Some("MOSSTOWN")),
+    ("Mosstown_02", Some("MOSSTOWN")),
+    ("Mosstown_02c", Some("MOSSTOWN")),
+    ("Mosstown_03", Some("MOSSTOWN")),
+    ("Opening_Sequence", None),
+    ("Opening_Sequence_Act3", None),
+    ("Organ_01", None),
+    ("Peak_01", Some("PEAK")),
+    ("Peak_02", Some("PEAK")),
+    ("Peak_03", Some("PEAK")),
+    ("Peak_04", Some("PEAK")),
+    ("Peak_05", Some("PEAK")),
+    ("Peak_05c", Some("PEAK")),
+    ("Peak_06", Some("PEAK")),
+    ("Peak_06b", Some("PEAK")),
+    ("Peak_07", Some("PEAK")),
+    ("Peak_08", Some("PEAK")),
+    ("Peak_09", Some("PEAK")),
+    ("Peak_10", Some("PEAK")),
+    ("Peak_11", Some("PEAK")),
+    ("Peak_12", Some("PEAK")),
+    ("Peak_13", Some("PEAK")),
+    ("Peak_14", Some("PEAK")),
+    ("Peak_15", Some("PEAK")),
+    ("Peak_16", Some("PEAK")),
+    ("Peak_17", Some("PEAK")),
+    ("Peak_18", Some("PEAK")),
+    ("Peak_19", Some("PEAK")),
+    ("PermaDeath", None),
+    ("Pre_Menu_Intro", None),
+    ("Quit_To_Menu", None),
+    ("Room_Caravan_Interior", None),
+    ("Room_Caravan_Spa", None),
+    ("Room_CrowCourt", None),
+    ("Room_CrowCourt_02", None),
+    ("Room_Diving_Bell", None),
+    ("Room_Diving_Bell_Abyss", None),
+    ("Room_Diving_Bell_Abyss_Fixed", None),
+    ("Room_Forge", None),
+    ("Room_Huntress", None),
+    ("Room_Pinstress", None),
+    ("Room_Witch", None),
+    ("Shadow_01", Some("SWAMP")),
+    ("Shadow_02", Some("SWAMP")),
+    ("Shadow_03", Some("SWAMP")),
+    ("Shadow_04", Some("SWAMP")),
+    ("Shadow_05", Some("SWAMP")),
+    ("Shadow_06", Some("SWAMP")),
+    ("Shadow_07", Some("SWAMP")),
+    ("Shadow_08", Some("SWAMP")),
+    ("Shadow_09", Some("SWAMP")),
+    ("Shadow_10", Some("SWAMP")),
+    ("Shadow_11", Some("SWAMP")),
+    ("Shadow_12", Some("SWAMP")),
+    ("Shadow_13", Some("SWAMP")),
+    ("Shadow_14", Some("SWAMP")),
+    ("Shadow_15", Some("SWAMP")),
+    ("Shadow_16", Some("SWAMP")),
+    ("Shadow_17", Some("SWAMP")),
+    ("Shadow_18", Some("SWAMP")),
+    ("Shadow_19", Some("SWAMP")),
+    ("Shadow_20", Some("SWAMP")),
+    ("Shadow_21", Some("SWAMP")),
+    ("Shadow_22", Some("SWAMP")),
+    ("Shadow_23", Some("SWAMP")),
+    ("Shadow_24", Some("SWAMP")),
+    ("Shadow_25", Some("SWAMP")),
+    ("Shadow_26", Some("SWAMP")),
+    ("Shadow_27", Some("SWAMP")),
+    ("Shadow_28", Some("SWAMP")),
+    ("Shadow_Weavehome", Some("SWAMP")),
+    ("Shellwood", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_01", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_02", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_03", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_04", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_05", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_06", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_07", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_08", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_09", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_10", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_11", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_11b", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_11b_Memory", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_12", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_13", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_14", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_15", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_16", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_17", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_18", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_19", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_20", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_21", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_22", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_23", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_24", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_25", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_26", Some("SHELLWOOD_THICKET")),
+    ("Shellwood_Witch", Some("SHELLWOOD_THICKET")),
+    ("Slab_01", Some("THE_SLAB")),
+    ("Slab_02", Some("THE_SLAB")),
+    ("Slab_03", Some("THE_SLAB")),
+    ("Slab_04", Some("THE_SLAB")),
+    ("Slab_05", Some("THE_SLAB")),
+    ("Slab_06", Some("THE_SLAB")),
+    ("Slab_07", Some("THE_SLAB")),
+    ("Slab_08", Some("THE_SLAB")),
+    ("Slab_09", Some("THE_SLAB")),
+    ("Slab_10", Some("THE_SLAB")),
+    ("Slab_10b", Some("THE_SLAB")),
+    ("Slab_10c", Some("THE_SLAB")),
+    ("Slab_11", Some("THE_SLAB")),
+    ("Slab_12", Some("THE_SLAB")),
+    ("Slab_13", Some("THE_SLAB")),
+    ("Slab_14", Some("THE_SLAB")),
+    ("Slab_15", Some("THE_SLAB")),
+    ("Slab_16", Some("THE_SLAB")),
+    ("Slab_17", Some("THE_SLAB")),
+    ("Slab_18", Some("THE_SLAB")),
+    ("Slab_19", Some("THE_SLAB")),
+    ("Slab_20", Some("THE_SLAB")),
+    ("Slab_21", Some("THE_SLAB")),
+    ("Slab_22", Some("THE_SLAB")),
+    ("Slab_23", Some("THE_SLAB")),
+    ("Slab_Cell", Some("THE_SLAB")),
+    ("Song_01", Some("CITY_OF_SONG")),
+    ("Song_01c", Some("CITY_OF_SONG")),
+    ("Song_02", Some("CITY_OF_SONG")),
+    ("Song_03", Some("CITY_OF_SONG")),
+    ("Song_04", Some("CITY_OF_SONG")),
+    ("Song_05", Some("CITY_OF_SONG")),
+    ("Song_06", Some("CITY_OF_SONG")),
+    ("Song_07", Some("CITY_OF_SONG")),
+    ("Song_08", Some("CITY_OF_SONG")),
+    ("Song_09", Some("CITY_OF_SONG")),
+    ("Song_10", Some("CITY_OF_SONG")),
+    ("Song_11", Some("CITY_OF_SONG")),
+    ("Song_12", Some("CITY_OF_SONG")),
+    ("Song_13", Some("CITY_OF_SONG")),
+    ("Song_14", Some("CITY_OF_SONG")),
+    ("Song_15", Some("CITY_OF_SONG")),
+    ("Song_16", Some("CITY_OF_SONG")),
+    ("Song_17", Some("CITY_OF_SONG")),
+    ("Song_18", Some("CITY_OF_SONG")),
+    ("Song_19", Some("CITY_OF_SONG")),
+    ("Song_19_entrance", Some("CITY_OF_SONG")),
+    ("Song_20", Some("CITY_OF_SONG")),
+    ("Song_20b", Some("CITY_OF_SONG")),
+    ("Song_21", Some("CITY_OF_SONG")),
+    ("Song_22", Some("CITY_OF_SONG")),
+    ("Song_23", Some("CITY_OF_SONG")),
+    ("Song_24", Some("CITY_OF_SONG")),
+    ("Song_25", Some("CITY_OF_SONG")),
+    ("Song_26", Some("CITY_OF_SONG")),
+    ("Song_27", Some("CITY_OF_SONG")),
+    ("Song_28", Some("CITY_OF_SONG")),
+    ("Song_29", Some("CITY_OF_SONG")),
+    ("Song_Enclave", Some("SONG_ENCLAVE")),
+    ("Song_Enclave_Tube", Some("SONG_ENCLAVE")),
+    ("Song_Tower_01", Some("CITY_OF_SONG")),
+    ("Song_Tower_Destroyed", Some("CITY_OF_SONG")),
+    ("Sprintmaster_Cave", Some("WILDS")),
+    ("Tube_Hub", None),
+    ("Tut_01", Some("MOSS_CAVE")),
+    ("Tut_01b", Some("MOSS_CAVE")),
+    ("Tut_02", Some("MOSS_CAVE")),
+    ("Tut_03", Some("MOSS_CAVE")),
+    ("Tut_04", Some("MOSS_CAVE")),
+    ("Tut_05", Some("MOSS_CAVE")),
+    ("Under_01", Some("UNDERSTORE")),
+    ("Under_02", Some("UNDERSTORE")),
+    ("Under_03", Some("UNDERSTORE")),
+    ("Under_04", Some("UNDERSTORE")),
+    ("Under_05", Some("UNDERSTORE")),
+    ("Under_06", Some("UNDERSTORE")),
+    ("Under_07", Some("UNDERSTORE")),
+    ("Under_07b", Some("UNDERSTORE")),
+    ("Under_08", Some("UNDERSTORE")),
+    ("Under_09", Some("UNDERSTORE")),
+    ("Under_10", Some("UNDERSTORE")),
+    ("Under_11", Some("UNDERSTORE")),
+    ("Under_12", Some("UNDERSTORE")),
+    ("Under_13", Some("UNDERSTORE")),
+    ("Under_14", Some("UNDERSTORE")),
+    ("Under_15", Some("UNDERSTORE")),
+    ("Under_16", Some("UNDERSTORE")),
+    ("Under_17", Some("UNDERSTORE")),
+    ("Under_17b", Some("UNDERSTORE")),
+    ("Under_18", Some("UNDERSTORE")),
+    ("Under_19", Some("UNDERSTORE")),
+    ("Under_20", Some("UNDERSTORE")),
+    ("Under_21", Some("UNDERSTORE")),
+    ("Under_22", Some("UNDERSTORE")),
+    ("Under_23", Some("UNDERSTORE")),
+    ("Under_24", Some("UNDERSTORE")),
+    ("Under_25", Some("UNDERSTORE")),
+    ("Under_26", Some("UNDERSTORE")),
+    ("Under_27", Some("UNDERSTORE")),
+    ("Ward_01", Some("WARD")),
+    ("Ward_02", Some("WARD")),
+    ("Ward_02b", Some("WARD")),
+    ("Ward_03", Some("WARD")),
+    ("Ward_04", Some("WARD")),
+    ("Ward_05", Some("WARD")),
+    ("Ward_06", Some("WARD")),
+    ("Ward_07", Some("WARD")),
+    ("Ward_08", Some("WARD")),
+    ("Ward_09", Some("WARD")),
+    ("Weave_01", None),
+    ("Weave_02", None),
+    ("Weave_03", None),
+    ("Weave_04", None),
+    ("Weave_05", None),
+    ("Weave_06", None),
+    ("Weave_07", None),
+    ("Weave_08", None),
+    ("Weave_09", None),
+    ("Weave_10", None),
+    ("Weave_11", None),
+    ("Weave_12", None),
+    ("Weave_13", None),
+    ("Weave_14", None),
+    ("Wisp_01", Some("WISP")),
+    ("Wisp_02", Some("WISP")),
+    ("Wisp_03", Some("WISP")),
+    ("Wisp_04", Some("WISP")),
+    ("Wisp_05", Some("WISP")),
+    ("Wisp_06", Some("WISP")),
+    ("Wisp_07", Some("WISP")),
+    ("Wisp_08", Some("WISP")),
+    ("Wisp_09", Some("WISP")),
+];
+
+fn find_scene(scene: &str) -> Option<&'static (&'static str, Option<&'static str>)> {
+    let i = SCENES.binary_search_by_key(&scene, |(name, _)| name).ok()?;
+    Some(&SCENES[i])
+}
+
+/// Whether the name is a real scene in the catalog
+pub fn is_known_scene(scene: &str) -> bool {
+    find_scene(scene).is_some()
+}
+
+/// The map zone of a scene, if the scene is all in one map zone
+pub fn scene_map_zone(scene: &str) -> Option<&'static str> {
+    find_scene(scene)?.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::area::{area_name, map_zone_area};
+
+    #[test]
+    fn scenes_are_sorted_for_lookup() {
+        assert!(SCENES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn rejects_names_that_are_not_scenes() {
+        assert!(is_known_scene("Bone_East_05"));
+        assert!(is_known_scene("Bonetown"));
+        assert!(!is_known_scene("Untagged"));
+        assert!(!is_known_scene("left1"));
+        assert!(!is_known_scene("Room_"));
+        assert!(!is_known_scene("Bone_East"));
+    }
+
+    #[test]
+    fn map_zone_and_catalog_areas_agree() {
+        for (scene, zone) in SCENES.iter() {
+            let Some(zone) = zone else {
+                continue;
+            };
+            assert!(map_zone_area(zone).is_some(), "{} has no area", zone);
+            assert_eq!(area_name(scene, Some(zone)), area_name(scene, None));
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use alloc::{collections::VecDeque, format, string::String, vec::Vec};
+
+use asr::{time::Duration, time_util::Instant};
+
+use crate::{timer::duration_string, transition::TransitionKind};
+
+/// How many transitions the history keeps
+const HISTORY_LEN: usize = 16;
+
+/// A room entered by a transition
+#[derive(Clone, Debug)]
+pub struct SceneVisit {
+    pub scene: String,
+    pub kind: TransitionKind,
+    pub gate: String,
+    /// When the transition happened, counted from when the auto splitter attached
+    pub time: Duration,
+}
+
+/// The most recent transitions, oldest first
+pub struct SceneHistory {
+    visits: VecDeque<SceneVisit>,
+    attached: Instant,
+}
+
+impl SceneHistory {
+    pub fn new() -> SceneHistory {
+        SceneHistory {
+            visits: VecDeque::with_capacity(HISTORY_LEN),
+            attached: Instant::now(),
+        }
+    }
+
+    pub fn push(&mut self, scene: &str, kind: TransitionKind, gate: &str) {
+        if self.visits.len() == HISTORY_LEN {
+            self.visits.pop_front();
+        }
+        self.visits.push_back(SceneVisit {
+            scene: scene.into(),
+            kind,
+            gate: gate.into(),
+            time: Instant::now() - self.attached,
+        });
+    }
+
+    /// The most recent transitions, newest first
+    pub fn recent(&self) -> impl Iterator<Item = &SceneVisit> {
+        self.visits.iter().rev()
+    }
+
+    /// Whether one of the scenes was entered within the last `n` transitions,
+    /// and by a transition of `kind` if given
+    pub fn entered_within(&self, scenes: &[&str], n: usize, kind: Option<TransitionKind>) -> bool {
+        self.recent()
+            .take(n)
+            .any(|v| scenes.contains(&v.scene.as_str()) && kind.is_none_or(|k| k == v.kind))
+    }
+
+    /// Whether one of the scenes was entered within the `n` transitions before the latest one,
+    /// such as the room the latest transition came from when `n` is 1
+    pub fn entered_before_latest(&self, scenes: &[&str], n: usize) -> bool {
+        self.recent()
+            .skip(1)
+            .take(n)
+            .any(|v| scenes.contains(&v.scene.as_str()))
+    }
+
+    /// The last `n` rooms, newest first,
+    /// like `Bone_05 left1 walk 1:23.4 | Tut_01 Death Respawn Marker Init save load 0:45.6`
+    pub fn recent_string(&self, n: usize) -> String {
+        let rooms: Vec<String> = self
+            .recent()
+            .take(n)
+            .map(|v| {
+                format!(
+                    "{} {} {} {}",
+                    v.scene,
+                    v.gate,
+                    v.kind.name(),
+                    duration_string(v.time)
+                )
+            })
+            .collect();
+        rooms.join(" | ")
+    }
+}
+
+impl Default for SceneHistory {
+    fn default() -> Self {
+        SceneHistory::new()
+    }
+}
@@ -7,7 +7,7 @@ use alloc::{
     vec::Vec,
 };
 use asr::{
-    future::next_tick,
+    future::{next_tick, retry},
     game_engine::unity::mono::{self, UnityPointer},
     timer::TimerState,
     watcher::Pair,
@@ -15,6 +15,12 @@ use asr::{
 };
 use bytemuck::CheckedBitPattern;
 
+use crate::{
+    scene_catalog::is_known_scene,
+    scene_history::SceneHistory,
+    transition::{classify_transition, SceneLoadInfo, TransitionKind},
+};
+
 // --------------------------------------------------------
 
 static SILKSONG_NAMES: [&str; 2] = [
@@ -36,19 +42,11 @@ pub static DEATH_RESPAWN_MARKER_INIT: &str = "Death Respawn Marker Init";
 pub const CINEMATIC_STAG_TRAVEL: &str = "Cinematic_Stag_travel";
 static DISCONTINUITY_SCENE_NAMES: [&str; 2] = ["Demo Start", CINEMATIC_STAG_TRAVEL];
 
-static BAD_SCENE_NAMES: [&str; 11] = [
-    "Untagged",
-    "left1",
-    "oncomplete",
-    "Attack Range",
-    "onstart",
-    "position",
-    "looptype",
-    "integer1",
-    "gameObject",
-    "eventTarget",
-    "material",
-];
+/// `GameManager.SceneLoadInfo.Visualization` when loading a save file
+pub const SCENE_LOAD_VISUALIZATION_CONTINUE_FROM_SAVE: i32 = 4;
+
+/// The most unknown scene names to remember, so each is only logged once
+const MAX_UNKNOWN_SCENE_NAMES: usize = 64;
 
 /*
 public enum GameState
@@ -94,6 +92,18 @@ pub const UI_STATE_PAUSED: i32 = 5;
 
 pub const SLOT_STATE_BLACK_THREAD_INFECTED: i32 = 9;
 
+/*
+public enum PermadeathModes
+{
+    Off,
+    On,
+    Dead
+}
+*/
+pub const PERMADEATH_MODE_OFF: i32 = 0;
+pub const PERMADEATH_MODE_ON: i32 = 1;
+pub const PERMADEATH_MODE_DEAD: i32 = 2;
+
 /*
 public enum HeroTransitionState
 {
@@ -261,6 +271,7 @@ declare_pointers!(GameManagerPointers {
     scene_name: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "sceneName"]),
     next_scene_name: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "nextSceneName"]),
     entry_gate_name: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "entryGateName"]),
+    map_zone_string: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "mapZoneString"]),
     game_state: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "<GameState>k__BackingField"]),
     ui_state_vanilla: UnityPointer<3> = UnityPointer::new(
         "GameManager",
@@ -376,6 +387,25 @@ declare_pointers!(GameManagerPointers {
         0,
         &["_instance", "sceneLoad", "<IsActivationAllowed>k__BackingField"],
     ),
+    scene_load_visualization: UnityPointer<4> = UnityPointer::new(
+        "GameManager",
+        0,
+        &["_instance", "sceneLoad", "SceneLoadInfo", "Visualization"],
+    ),
+    scene_load_entry_gate_name: UnityPointer<4> = UnityPointer::new(
+        "GameManager",
+        0,
+        &["_instance", "sceneLoad", "SceneLoadInfo", "EntryGateName"],
+    ),
+    scene_load_transition_id: UnityPointer<4> = UnityPointer::new(
+        "GameManager",
+        0,
+        &["_instance", "sceneLoad", "SceneLoadInfo", "TransitionID"],
+    ),
+    hazard_respawning_hero: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "hazardRespawningHero"]),
+    is_in_scene_transition: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "<IsInSceneTransition>k__BackingField"]),
+    is_loading: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "isLoading"]),
+    current_load_duration: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "currentLoadDuration"]),
 });
 
 #[inline(never)]
@@ -387,7 +417,10 @@ declare_pointers!(PlayerDataPointers {
     version: UnityPointer<3> = pdp("version"),
     disable_pause: UnityPointer<3> = pdp("disablePause"),
     is_inventory_open: UnityPointer<3> = pdp("isInventoryOpen"),
+    is_teleporting: UnityPointer<3> = pdp("IsTeleporting"),
+    travelling: UnityPointer<3> = pdp("travelling"),
     health: UnityPointer<3> = pdp("health"),
+    play_time: UnityPointer<3> = pdp("playTime"),
     max_health_base: UnityPointer<3> = pdp("maxHealthBase"),
     heart_pieces: UnityPointer<3> = pdp("heartPieces"),
     at_bench: UnityPointer<3> = pdp("atBench"),
@@ -626,12 +659,48 @@ declare_pointers!(PlayerDataPointers {
     encountered_plasmified_zango: UnityPointer<3> = pdp("BlueAssistantEnemyEncountered"),
     encountered_lost_lace: UnityPointer<3> = pdp("EncounteredLostLace"),
     completion_percentage: UnityPointer<3> = pdp("completionPercentage"),
+    geo: UnityPointer<3> = pdp("geo"),
+    temp_geo_store: UnityPointer<3> = pdp("TempGeoStore"),
+    hero_corpse_money_pool: UnityPointer<3> = pdp("HeroCorpseMoneyPool"),
+    shell_shards: UnityPointer<3> = pdp("ShellShards"),
+    permadeath_mode: UnityPointer<3> = pdp("permadeathMode"),
+    steel_quest_spots: UnityPointer<3> = pdp("SteelQuestSpots"),
+    boss_rush_mode: UnityPointer<3> = pdp("bossRushMode"),
+    // _instance.playerData.currentBossSequence is a BossSequenceController.BossSequenceData
+    // at 0x170 in Silksong-Mono-dissector.TXT, resolved by field name since its fields aren't in the dump
+    boss_sequence_index: UnityPointer<4> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "currentBossSequence", "bossIndex"]),
+    completed_endings: UnityPointer<3> = pdp("CompletedEndings"),
+    last_completed_ending: UnityPointer<3> = pdp("LastCompletedEnding"),
 
     // asr currently struggles to resolve these by name consistently
     // _instance.playerData.Tools.RuntimeData._version
     tools_version: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Tools", "0x18", "0x4c"]),
     // _instance.playerData.Tools.RuntimeData._entries
     tools_entries: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Tools", "0x18", "0x18"]),
+    // _instance.playerData.QuestCompletionData.RuntimeData._version
+    quests_version: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "QuestCompletionData", "0x18", "0x4c"]),
+    // _instance.playerData.QuestCompletionData.RuntimeData._entries
+    quests_entries: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "QuestCompletionData", "0x18", "0x18"]),
+    // _instance.playerData.EnemyJournalKillData.dictionary._version
+    journal_version: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "EnemyJournalKillData", "0x18", "0x4c"]),
+    // _instance.playerData.EnemyJournalKillData.dictionary._entries
+    journal_entries: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "EnemyJournalKillData", "0x18", "0x18"]),
+    // _instance.playerData.ToolEquips.RuntimeData._version
+    crests_version: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "ToolEquips", "0x18", "0x4c"]),
+    // _instance.playerData.ToolEquips.RuntimeData._entries
+    crests_entries: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "ToolEquips", "0x18", "0x18"]),
+    // _instance.playerData.Collectables.RuntimeData._version
+    collectables_version: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Collectables", "0x18", "0x4c"]),
+    // _instance.playerData.Collectables.RuntimeData._entries
+    collectables_entries: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Collectables", "0x18", "0x18"]),
+    // _instance.playerData.Relics.RuntimeData._version
+    relics_version: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Relics", "0x18", "0x4c"]),
+    // _instance.playerData.Relics.RuntimeData._entries
+    relics_entries: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Relics", "0x18", "0x18"]),
+    // _instance.playerData.MementosDeposited.RuntimeData._version
+    mementos_version: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "MementosDeposited", "0x18", "0x4c"]),
+    // _instance.playerData.MementosDeposited.RuntimeData._entries
+    mementos_entries: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "MementosDeposited", "0x18", "0x18"]),
 });
 
 // --------------------------------------------------------
@@ -644,19 +713,27 @@ pub struct Memory<'a> {
 }
 
 impl Memory<'_> {
-    pub async fn wait_attach<'a>(process: &'a Process) -> Memory<'a> {
+    /// Waits for the Mono module and image, calling `on_tick` on every tick until then
+    pub async fn wait_attach<'a>(process: &'a Process, mut on_tick: impl FnMut()) -> Memory<'a> {
         asr::print_message("Memory wait_attach: Module wait_attach...");
+        on_tick();
         next_tick().await;
         let mut found_module = false;
         let mut needed_retry = false;
         loop {
-            let module = mono::Module::wait_attach(process, mono::Version::V3).await;
+            let module = retry(|| {
+                on_tick();
+                mono::Module::attach(process, mono::Version::V3)
+            })
+            .await;
             if !found_module {
                 found_module = true;
                 asr::print_message("Memory wait_attach: module get_default_image...");
+                on_tick();
                 next_tick().await;
             }
             for _ in 0..0x10 {
+                on_tick();
                 if let Some(image) = module.get_default_image(process) {
                     asr::print_message(&format!(
                         "Memory wait_attach: got module and image, pointer_size = {:?}, Mono version = {:?}",
@@ -676,6 +753,7 @@ impl Memory<'_> {
             if !needed_retry {
                 needed_retry = true;
                 asr::print_message("Memory wait_attach: retry...");
+                on_tick();
                 next_tick().await;
             }
         }
@@ -723,7 +801,7 @@ impl<'a> Env<'a> {
 // --------------------------------------------------------
 
 pub struct SceneStore {
-    pub prev_scene_name: String,
+    prev_scene_name: String,
     curr_scene_name: String,
     next_scene_name: String,
     new_data_curr: bool,
@@ -731,7 +809,15 @@ pub struct SceneStore {
     last_next: bool,
     last_changed: bool,
     last_scene_load_activation_allowed: bool,
+    last_scene_load_null: bool,
+    last_transition_id: Option<i32>,
+    classified_this_transition: bool,
     pub split_this_transition: bool,
+    unknown_scene_names: Vec<String>,
+    last_transition_kind: TransitionKind,
+    last_entry_gate: String,
+    load_info: Option<SceneLoadInfo>,
+    history: SceneHistory,
 }
 
 impl SceneStore {
@@ -745,7 +831,15 @@ impl SceneStore {
             last_next: true,
             last_changed: false,
             last_scene_load_activation_allowed: false,
+            last_scene_load_null: true,
+            last_transition_id: None,
+            classified_this_transition: false,
             split_this_transition: false,
+            unknown_scene_names: Vec::new(),
+            last_transition_kind: TransitionKind::default(),
+            last_entry_gate: "".to_string(),
+            load_info: None,
+            history: SceneHistory::new(),
         }
     }
 
@@ -767,11 +861,37 @@ impl SceneStore {
         self.last_changed && self.pair().changed()
     }
 
-    pub fn new_curr_scene_name(&mut self, csn: String) {
-        if !csn.is_empty()
-            && csn != self.curr_scene_name
-            && !BAD_SCENE_NAMES.contains(&csn.as_str())
+    /// The kind of the most recent transition
+    pub fn transition_kind(&self) -> TransitionKind {
+        self.last_transition_kind
+    }
+
+    /// The entry gate of the most recent transition, such as `left1`
+    pub fn entry_gate(&self) -> &str {
+        &self.last_entry_gate
+    }
+
+    /// The most recent transitions
+    pub fn history(&self) -> &SceneHistory {
+        &self.history
+    }
+
+    /// Whether the name is a known scene, logging unknown names once
+    fn is_known_scene_name(&mut self, name: &str) -> bool {
+        if is_known_scene(name) {
+            return true;
+        }
+        if self.unknown_scene_names.len() < MAX_UNKNOWN_SCENE_NAMES
+            && !self.unknown_scene_names.iter().any(|n| n == name)
         {
+            asr::print_message(&format!("Unknown scene name: {}", name));
+            self.unknown_scene_names.push(name.to_string());
+        }
+        false
+    }
+
+    pub fn new_curr_scene_name(&mut self, csn: String) {
+        if !csn.is_empty() && csn != self.curr_scene_name && self.is_known_scene_name(&csn) {
             self.prev_scene_name = mem::replace(&mut self.curr_scene_name, csn);
             #[cfg(debug_assertions)]
             asr::print_message(&format!("curr_scene_name: {}", self.curr_scene_name));
@@ -780,10 +900,7 @@ impl SceneStore {
     }
 
     pub fn new_next_scene_name(&mut self, nsn: String) {
-        if !nsn.is_empty()
-            && nsn != self.next_scene_name
-            && !BAD_SCENE_NAMES.contains(&nsn.as_str())
-        {
+        if !nsn.is_empty() && nsn != self.next_scene_name && self.is_known_scene_name(&nsn) {
             self.next_scene_name = nsn;
             #[cfg(debug_assertions)]
             asr::print_message(&format!("next_scene_name: {}", self.next_scene_name));
@@ -791,6 +908,30 @@ impl SceneStore {
         }
     }
 
+    /// Records the kind and entry gate of the transition happening now,
+    /// once per transition even when several of its steps are seen
+    fn classify(&mut self, e: &Env) {
+        if self.classified_this_transition
+            && self
+                .history
+                .recent()
+                .next()
+                .is_some_and(|v| v.scene == self.pair().current)
+        {
+            return;
+        }
+        self.classified_this_transition = true;
+        let load_info = self.load_info.take();
+        self.last_transition_kind = classify_transition(self.pair(), load_info.as_ref(), e);
+        self.last_entry_gate = match load_info {
+            Some(info) if !info.entry_gate_name.is_empty() => info.entry_gate_name,
+            _ => e.mem.read_string(&e.gm.entry_gate_name).unwrap_or_default(),
+        };
+        let current = self.pair().current.to_string();
+        self.history
+            .push(&current, self.last_transition_kind, &self.last_entry_gate);
+    }
+
     pub fn transition_now(&mut self, e: &Env) -> bool {
         let Env { mem, gm, .. } = e;
         self.new_curr_scene_name(mem.read_string(&gm.scene_name).unwrap_or_default());
@@ -800,6 +941,25 @@ impl SceneStore {
         let scene_load_activation_allowed: bool = mem
             .deref(&gm.scene_load_activation_allowed)
             .unwrap_or_default();
+        let load_info = if scene_load_null {
+            None
+        } else {
+            SceneLoadInfo::read(e)
+        };
+        let new_transition_id = load_info
+            .as_ref()
+            .is_some_and(|i| Some(i.transition_id) != self.last_transition_id);
+        if new_transition_id || (!scene_load_null && self.last_scene_load_null) {
+            // a new scene load, so the next transition is a new one
+            self.classified_this_transition = false;
+        }
+        self.last_scene_load_null = scene_load_null;
+        if let Some(info) = load_info {
+            self.last_transition_id = Some(info.transition_id);
+            if !self.classified_this_transition {
+                self.load_info = Some(info);
+            }
+        }
         if scene_load_null || scene_load_activation_allowed {
             self.new_next_scene_name(mem.read_string(&gm.next_scene_name).unwrap_or_default());
         }
@@ -813,6 +973,7 @@ impl SceneStore {
             self.last_next = true;
             self.last_changed = true;
             self.split_this_transition = false;
+            self.classify(e);
             #[cfg(debug_assertions)]
             asr::print_message(&format!(
                 "curr {} -> next {}",
@@ -838,6 +999,7 @@ impl SceneStore {
             if is_menu(&self.prev_scene_name) || is_menu(&self.curr_scene_name) {
                 self.split_this_transition = false;
             }
+            self.classify(e);
             #[cfg(debug_assertions)]
             asr::print_message(&format!(
                 "prev {} -> curr {}",
@@ -847,6 +1009,7 @@ impl SceneStore {
         } else if new_scene_load_activation_allowed {
             self.last_changed = false;
             self.split_this_transition = false;
+            self.classify(e);
             #[cfg(debug_assertions)]
             asr::print_message(&format!(
                 "curr {} =? next {}",
@@ -867,58 +1030,322 @@ impl Default for SceneStore {
 
 // --------------------------------------------------------
 
-pub fn get_tools_version(mem: &Memory, pd: &PlayerDataPointers) -> Option<i32> {
-    mem.deref(&pd.tools_version).ok()
+/// A value in the `RuntimeData` dictionary of a `SerializableNamedList`
+pub trait NamedValue: Sized {
+    fn read(mem: &Memory, p_value: Address64) -> Option<Self>;
 }
 
-pub fn find_tool(tool_utf16: &[u16], mem: &Memory, pd: &PlayerDataPointers) -> Option<(i32, bool)> {
-    asr::print_message("Scanning tools");
-    const MAX_TOOL_ID_LENGTH: usize = 32; // The longest seems to be 20 but I rounded up
+/// ToolItemsData.Data
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ToolData {
+    pub is_unlocked: bool,
+}
 
-    let buf = &mut [0; MAX_TOOL_ID_LENGTH][..tool_utf16.len()];
+impl NamedValue for ToolData {
+    fn read(mem: &Memory, p_value: Address64) -> Option<Self> {
+        Some(ToolData {
+            is_unlocked: mem.process.read(p_value).ok()?,
+        })
+    }
+}
 
-    let p_entries = mem.deref::<Address64, _>(&pd.tools_entries).ok()?;
+/// QuestCompletionData.Completion
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct QuestCompletion {
+    pub is_accepted: bool,
+    pub completed_count: i32,
+    pub is_completed: bool,
+    pub was_ever_completed: bool,
+}
 
-    let len_entries = mem.process.read::<i32>(p_entries + 0x18).ok()?;
+impl QuestCompletion {
+    pub fn is_done(&self) -> bool {
+        self.is_completed || self.was_ever_completed
+    }
+}
 
-    if len_entries > 131 {
-        return None;
+impl NamedValue for QuestCompletion {
+    fn read(mem: &Memory, p_value: Address64) -> Option<Self> {
+        Some(QuestCompletion {
+            is_accepted: mem.process.read(p_value + 0x1).ok()?,
+            completed_count: mem.process.read(p_value + 0x4).ok()?,
+            is_completed: mem.process.read(p_value + 0x8).ok()?,
+            was_ever_completed: mem.process.read(p_value + 0x9).ok()?,
+        })
     }
+}
 
-    for i in 0..len_entries {
-        let p_string: Address64 = mem.process.read(p_entries + 0x28 + 0x18 * i).ok()?;
+/// EnemyJournalKillData.KillData
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct KillData {
+    pub kills: i32,
+    pub has_been_seen: bool,
+}
 
-        let len_string: i32 = mem
-            .process
-            .read(p_string + mem.string_list_offsets.string_len)
-            .ok()?;
+impl NamedValue for KillData {
+    fn read(mem: &Memory, p_value: Address64) -> Option<Self> {
+        Some(KillData {
+            kills: mem.process.read(p_value).ok()?,
+            has_been_seen: mem.process.read(p_value + 0x4).ok()?,
+        })
+    }
+}
 
-        if len_string != tool_utf16.len() as i32 {
-            continue;
-        }
+/// ToolCrestsData.Data, unlocked is the first field
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CrestData {
+    pub is_unlocked: bool,
+}
 
-        mem.process
-            .read_into_slice(p_string + mem.string_list_offsets.string_contents, buf)
-            .ok()?;
+impl NamedValue for CrestData {
+    fn read(mem: &Memory, p_value: Address64) -> Option<Self> {
+        Some(CrestData {
+            is_unlocked: mem.process.read(p_value).ok()?,
+        })
+    }
+}
 
-        if buf != tool_utf16 {
-            continue;
-        }
+/// CollectableItemsData.Data, the amount is the first field
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CollectableData {
+    pub amount: i32,
+}
+
+impl NamedValue for CollectableData {
+    fn read(mem: &Memory, p_value: Address64) -> Option<Self> {
+        Some(CollectableData {
+            amount: mem.process.read(p_value).ok()?,
+        })
+    }
+}
 
-        let is_unlocked: bool = mem.process.read(p_entries + 0x30 + 0x18 * i).ok()?;
+/// CollectableRelicsData.Data
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RelicData {
+    pub is_collected: bool,
+    pub is_deposited: bool,
+}
 
-        return Some((i, is_unlocked));
+impl NamedValue for RelicData {
+    fn read(mem: &Memory, p_value: Address64) -> Option<Self> {
+        Some(RelicData {
+            is_collected: mem.process.read(p_value).ok()?,
+            is_deposited: mem.process.read(p_value + 0x1).ok()?,
+        })
     }
+}
 
-    None
+/// CollectableMementosData.Data
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MementoData {
+    pub is_deposited: bool,
 }
 
-pub fn read_tool(i: i32, mem: &Memory, pd: &PlayerDataPointers) -> Option<bool> {
-    let p_entries = mem.deref::<Address64, _>(&pd.tools_entries).ok()?;
+impl NamedValue for MementoData {
+    fn read(mem: &Memory, p_value: Address64) -> Option<Self> {
+        Some(MementoData {
+            is_deposited: mem.process.read(p_value).ok()?,
+        })
+    }
+}
 
-    let is_unlocked: bool = mem.process.read(p_entries + 0x30 + 0x18 * i).ok()?;
+/// Where to find the `RuntimeData` dictionary of a `SerializableNamedList` in PlayerData.
+///
+/// Each dictionary entry is `{ hashCode: i32, next: i32, key: String, value: Data }`,
+/// so in the `_entries` array, after its 0x20 header,
+/// the hash is at 0x20, next at 0x24, the key at 0x28, and the value at 0x30.
+/// The entry size is 0x10 plus the size of the value, rounded up to 8
+pub struct NamedList {
+    name: &'static str,
+    version: fn(&PlayerDataPointers) -> &UnityPointer<5>,
+    entries: fn(&PlayerDataPointers) -> &UnityPointer<5>,
+    /// The distance between entries, with the key at 0x28 and the value at 0x30
+    entry_size: i32,
+    max_entries: i32,
+}
 
-    Some(is_unlocked)
+/// ToolItemsData+Data in Silksong-Mono-dissector.TXT:
+/// IsUnlocked 0x10, IsHidden 0x11, HasBeenSeen 0x12, HasBeenSelected 0x13, AmountLeft 0x14,
+/// so the value is 8 bytes
+pub static TOOLS_LIST: NamedList = NamedList {
+    name: "tools",
+    version: |pd| &pd.tools_version,
+    entries: |pd| &pd.tools_entries,
+    entry_size: 0x18,
+    max_entries: 131,
+};
+
+/// QuestCompletionData+Completion in Silksong-Mono-dissector.TXT:
+/// HasBeenSeen 0x10, IsAccepted 0x11, CompletedCount 0x14, IsCompleted 0x18, WasEverCompleted 0x19,
+/// so the value is 0x10 bytes
+pub static QUESTS_LIST: NamedList = NamedList {
+    name: "quests",
+    version: |pd| &pd.quests_version,
+    entries: |pd| &pd.quests_entries,
+    entry_size: 0x20,
+    max_entries: 1000,
+};
+
+/// EnemyJournalKillData+KillData in Silksong-Mono-dissector.TXT:
+/// Kills 0x10, HasBeenSeen 0x14, so the value is 8 bytes
+pub static JOURNAL_LIST: NamedList = NamedList {
+    name: "journal",
+    version: |pd| &pd.journal_version,
+    entries: |pd| &pd.journal_entries,
+    entry_size: 0x18,
+    max_entries: 1000,
+};
+
+/// ToolCrestsData+Data is not in Silksong-Mono-dissector.TXT.
+/// From the game's ToolCrestsData.Data: IsUnlocked bool, Slots List, DisplayNewIndicator bool,
+/// so the value is 0x18 bytes, with IsUnlocked at 0 and Slots at 8.
+/// `NamedList::for_each` checks each entry's next index, so a wrong size reads nothing
+pub static CRESTS_LIST: NamedList = NamedList {
+    name: "crests",
+    version: |pd| &pd.crests_version,
+    entries: |pd| &pd.crests_entries,
+    entry_size: 0x28,
+    max_entries: 100,
+};
+
+/// CollectableItemsData+Data is not in Silksong-Mono-dissector.TXT.
+/// From the game's CollectableItemsData.Data: Amount int, IsSeen bool, AmountWhileHidden int,
+/// so the value is 0xc bytes, rounded up to 0x10, with Amount at 0.
+/// `NamedList::for_each` checks each entry's next index, so a wrong size reads nothing
+pub static COLLECTABLES_LIST: NamedList = NamedList {
+    name: "collectables",
+    version: |pd| &pd.collectables_version,
+    entries: |pd| &pd.collectables_entries,
+    entry_size: 0x20,
+    max_entries: 1000,
+};
+
+/// CollectableRelicsData+Data is not in Silksong-Mono-dissector.TXT.
+/// From the game's CollectableRelicsData.Data: IsCollected bool, IsDeposited bool, HasSeenInRelicBoard bool,
+/// so the value is 3 bytes, rounded up to 8, with IsCollected at 0 and IsDeposited at 1.
+/// `NamedList::for_each` checks each entry's next index, so a wrong size reads nothing
+pub static RELICS_LIST: NamedList = NamedList {
+    name: "relics",
+    version: |pd| &pd.relics_version,
+    entries: |pd| &pd.relics_entries,
+    entry_size: 0x18,
+    max_entries: 1000,
+};
+
+/// CollectableMementosData+Data is not in Silksong-Mono-dissector.TXT.
+/// From the game's CollectableMementosData.Data: IsDeposited bool, HasSeenInRelicBoard bool,
+/// so the value is 2 bytes, rounded up to 8, with IsDeposited at 0.
+/// `NamedList::for_each` checks each entry's next index, so a wrong size reads nothing
+pub static MEMENTOS_LIST: NamedList = NamedList {
+    name: "mementos",
+    version: |pd| &pd.mementos_version,
+    entries: |pd| &pd.mementos_entries,
+    entry_size: 0x18,
+    max_entries: 1000,
+};
+
+impl NamedList {
+    pub fn get_version(&self, mem: &Memory, pd: &PlayerDataPointers) -> Option<i32> {
+        mem.deref((self.version)(pd)).ok()
+    }
+
+    pub fn find<T: NamedValue>(
+        &self,
+        name_utf16: &[u16],
+        mem: &Memory,
+        pd: &PlayerDataPointers,
+    ) -> Option<(i32, T)> {
+        asr::print_message(&format!("Scanning {}", self.name));
+        let mut found = None;
+        self.for_each_indexed(mem, pd, |i, name, value| {
+            if found.is_none() && name == name_utf16 {
+                found = Some((i, value));
+            }
+        })?;
+        found
+    }
+
+    pub fn read<T: NamedValue>(&self, i: i32, mem: &Memory, pd: &PlayerDataPointers) -> Option<T> {
+        let p_entries = mem.deref::<Address64, _>((self.entries)(pd)).ok()?;
+
+        T::read(mem, p_entries + 0x30 + self.entry_size * i)
+    }
+
+    /// Calls `f` with the name and value of every entry,
+    /// producing None if the list could not be read
+    pub fn for_each<T: NamedValue>(
+        &self,
+        mem: &Memory,
+        pd: &PlayerDataPointers,
+        mut f: impl FnMut(&[u16], T),
+    ) -> Option<()> {
+        self.for_each_indexed(mem, pd, |_, name, value| f(name, value))
+    }
+
+    fn for_each_indexed<T: NamedValue>(
+        &self,
+        mem: &Memory,
+        pd: &PlayerDataPointers,
+        mut f: impl FnMut(i32, &[u16], T),
+    ) -> Option<()> {
+        const MAX_NAME_LENGTH: usize = 64;
+
+        let mut buf = [0; MAX_NAME_LENGTH];
+
+        let p_entries = mem.deref::<Address64, _>((self.entries)(pd)).ok()?;
+
+        let len_entries = mem.process.read::<i32>(p_entries + 0x18).ok()?;
+
+        if len_entries > self.max_entries {
+            return None;
+        }
+
+        for i in 0..len_entries {
+            let p_string: Address64 = mem
+                .process
+                .read(p_entries + 0x28 + self.entry_size * i)
+                .ok()?;
+
+            if p_string.is_null() {
+                continue;
+            }
+
+            // a used entry's next is the index of another entry, or -1 at the end of its bucket,
+            // so anything else means the entry size doesn't match the game's
+            let next: i32 = mem
+                .process
+                .read(p_entries + 0x24 + self.entry_size * i)
+                .ok()?;
+            if !(-1..len_entries).contains(&next) {
+                asr::print_message(&format!(
+                    "Entries of {} don't match the expected layout",
+                    self.name
+                ));
+                return None;
+            }
+
+            let len_string: i32 = mem
+                .process
+                .read(p_string + mem.string_list_offsets.string_len)
+                .ok()?;
+
+            if len_string < 0 || MAX_NAME_LENGTH < len_string as usize {
+                continue;
+            }
+
+            let name = &mut buf[..len_string as usize];
+
+            mem.process
+                .read_into_slice(p_string + mem.string_list_offsets.string_contents, name)
+                .ok()?;
+
+            let value = T::read(mem, p_entries + 0x30 + self.entry_size * i)?;
+
+            f(i, name, value);
+        }
+
+        Some(())
+    }
 }
 
 // --------------------------------------------------------
@@ -956,6 +1383,119 @@ pub fn get_silk_spool_parts(e: Option<&Env>) -> Option<i32> {
     e?.mem.deref(&e?.pd.silk_spool_parts).ok()
 }
 
+pub fn get_geo(e: Option<&Env>) -> Option<i32> {
+    e?.mem.deref(&e?.pd.geo).ok()
+}
+
+pub fn get_permadeath_mode(e: Option<&Env>) -> Option<i32> {
+    e?.mem.deref(&e?.pd.permadeath_mode).ok()
+}
+
+/// How many of the Steel Soul quest spots have been seen, out of how many there are
+pub fn get_steel_quest_spots(e: Option<&Env>) -> Option<(i32, i32)> {
+    // PlayerData.SteelQuestSpots is a SteelSoulQuestSpot.Spot[] at 0x100 in Silksong-Mono-dissector.TXT,
+    // but Spot itself is not in the dump.
+    // From the game's SteelSoulQuestSpot.Spot class: SceneName string at 0x10, IsSeen bool at 0x18,
+    // so a spot without a scene name means the layout doesn't match
+    const MAX_SPOTS: i32 = 100;
+
+    let Env { mem, pd, .. } = e?;
+    let p_array: Address64 = mem.deref(&pd.steel_quest_spots).ok()?;
+    let len: i32 = mem.process.read(p_array + 0x18).ok()?;
+    if !(0..=MAX_SPOTS).contains(&len) {
+        return None;
+    }
+    let mut seen = 0;
+    for i in 0..len {
+        let p_spot: Address64 = mem.process.read(p_array + 0x20 + 8 * i).ok()?;
+        if p_spot.is_null() {
+            continue;
+        }
+        if mem.process.read::<Address64>(p_spot + 0x10).ok()?.is_null() {
+            return None;
+        }
+        if mem.process.read::<bool>(p_spot + 0x18).ok()? {
+            seen += 1;
+        }
+    }
+    Some((seen, len))
+}
+
+pub fn get_steel_quest_spots_seen(e: Option<&Env>) -> Option<i32> {
+    get_steel_quest_spots(e).map(|(seen, _)| seen)
+}
+
+pub fn get_boss_rush_mode(e: Option<&Env>) -> Option<bool> {
+    e?.mem.deref(&e?.pd.boss_rush_mode).ok()
+}
+
+/// The defeat flags of the bosses that a boss rush sequence can contain
+static BOSS_DEFEATED_FLAGS: [fn(&PlayerDataPointers) -> &UnityPointer<3>; 36] = [
+    |pd| &pd.defeated_moss_mother,
+    |pd| &pd.defeated_bell_beast,
+    |pd| &pd.defeated_lace1,
+    |pd| &pd.defeated_song_golem,
+    |pd| &pd.defeated_vampire_gnat_boss,
+    |pd| &pd.defeated_sister_splinter,
+    |pd| &pd.spinner_defeated,
+    |pd| &pd.defeated_last_judge,
+    |pd| &pd.defeated_phantom,
+    |pd| &pd.defeated_cogwork_dancers,
+    |pd| &pd.defeated_song_chevalier_boss,
+    |pd| &pd.defeated_trobbio,
+    |pd| &pd.defeated_tormented_trobbio,
+    |pd| &pd.defeated_lace_tower,
+    |pd| &pd.defeated_wisp_pyre_effigy,
+    |pd| &pd.defeated_first_weaver,
+    |pd| &pd.defeated_ant_trapper,
+    |pd| &pd.skarrgard_3_defeated,
+    |pd| &pd.defeated_flower_queen,
+    |pd| &pd.defeated_coral_king,
+    |pd| &pd.defeated_ant_queen,
+    |pd| &pd.defeated_clover_dancers,
+    |pd| &pd.defeated_white_cloverstag,
+    |pd| &pd.defeated_dock_foremen,
+    |pd| &pd.defeated_swamp_shaman,
+    |pd| &pd.defeated_bone_flyer_giant,
+    |pd| &pd.defeated_roach_keeper_chef,
+    |pd| &pd.defeated_brood_mother,
+    |pd| &pd.ward_boss_defeated,
+    |pd| &pd.skull_king_defeated,
+    |pd| &pd.defeated_seth,
+    |pd| &pd.defeated_crawfather,
+    |pd| &pd.defeated_coral_drillers,
+    |pd| &pd.defeated_zap_core_enemy,
+    |pd| &pd.defeated_grey_warrior,
+    |pd| &pd.defeated_lost_garmond,
+];
+
+/// How many of the `BOSS_DEFEATED_FLAGS` are set
+/// The index of the current boss in the boss rush sequence
+pub fn get_boss_sequence_index(e: Option<&Env>) -> Option<i32> {
+    e?.mem.deref(&e?.pd.boss_sequence_index).ok()
+}
+
+pub fn get_bosses_defeated(e: Option<&Env>) -> Option<i32> {
+    let Env { mem, pd, .. } = e?;
+    let mut defeated = 0;
+    for flag in BOSS_DEFEATED_FLAGS.iter() {
+        defeated += mem.deref::<bool, _>(flag(pd)).ok()? as i32;
+    }
+    Some(defeated)
+}
+
+pub fn get_completed_endings(e: Option<&Env>) -> Option<i32> {
+    e?.mem.deref(&e?.pd.completed_endings).ok()
+}
+
+pub fn get_current_crest(e: Option<&Env>) -> Option<String> {
+    let Env { mem, pd, gm } = e?;
+    if !is_game_state_non_menu(mem.deref(&gm.game_state).ok()?) {
+        return None;
+    }
+    mem.read_string(&pd.current_crest_id)
+}
+
 pub fn get_at_bench(e: Option<&Env>) -> Option<bool> {
     e?.mem.deref(&e?.pd.at_bench).ok()
 }
@@ -964,6 +1504,11 @@ pub fn get_is_maggoted(e: Option<&Env>) -> Option<bool> {
     e?.mem.deref(&e?.gm.hero_is_maggoted).ok()
 }
 
+pub fn get_map_zone(e: Option<&Env>) -> Option<String> {
+    let Env { mem, gm, .. } = e?;
+    mem.read_string(&gm.map_zone_string)
+}
+
 pub fn get_respawn_scene(e: Option<&Env>) -> Option<String> {
     let Env { mem, pd, gm } = e?;
     if !is_game_state_non_menu(mem.deref(&gm.game_state).ok()?) {
@@ -0,0 +1,186 @@
+use alloc::{format, string::String, vec::Vec};
+use asr::settings::{gui::Widget, Gui};
+use ugly_widget::store::StoreWidget;
+
+use crate::{act::Act, splits::Split};
+
+/// Which act a split is limited to
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, PartialEq)]
+pub enum ActLimit {
+    /// Any Act
+    #[default]
+    AnyAct,
+    /// Only in Act 1
+    Act1,
+    /// Only in Act 2
+    Act2,
+    /// Only in Act 3
+    Act3,
+}
+
+impl ActLimit {
+    pub fn act(self) -> Option<Act> {
+        match self {
+            ActLimit::AnyAct => None,
+            ActLimit::Act1 => Some(Act::Act1),
+            ActLimit::Act2 => Some(Act::Act2),
+            ActLimit::Act3 => Some(Act::Act3),
+        }
+    }
+
+    /// The option key the choice is stored as in the settings
+    pub fn key(self) -> &'static str {
+        match self {
+            ActLimit::AnyAct => "AnyAct",
+            ActLimit::Act1 => "Act1",
+            ActLimit::Act2 => "Act2",
+            ActLimit::Act3 => "Act3",
+        }
+    }
+}
+
+/// One entry of the splits list: the split,
+/// with the parameters that named and threshold splits read,
+/// and what the split is limited to beyond its own condition.
+///
+/// The split is stored under the entry's own key, such as `splits_3_item`,
+/// and everything else under a key next to it, such as `splits_3_item_name`,
+/// so they move together when the list is reordered
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SplitEntry {
+    pub split: Split,
+    /// The scene, quest, enemy, or other ID that a named split looks for,
+    /// from the `{key}_name` setting
+    pub name: String,
+    /// The name as UTF-16, to look up in the game's named lists
+    pub name_utf16: Vec<u16>,
+    /// The amount that a threshold split waits for,
+    /// from the `{key}_amount` setting
+    pub amount: i64,
+    /// Only split in this act, from the `{key}_act` choice
+    pub act: ActLimit,
+    /// Only split on transitions through this entry gate,
+    /// from the `{key}_gate` setting, or any gate when empty
+    pub gate: String,
+    /// Only split on the first entry into a room during the run,
+    /// from the `{key}_first_visit` checkbox
+    pub first_visit: bool,
+}
+
+impl SplitEntry {
+    /// Reads the parameters that have no GUI widget,
+    /// so they are set by the splits file or by editing the settings
+    fn update_params(&mut self, settings_map: &asr::settings::Map, key: &str) {
+        let name = settings_map
+            .get(&format!("{}_name", key))
+            .and_then(|v| v.get_string())
+            .unwrap_or_default();
+        if name != self.name {
+            self.name_utf16 = name.encode_utf16().collect();
+            self.name = name;
+        }
+        self.amount = settings_map
+            .get(&format!("{}_amount", key))
+            .and_then(|v| v.get_i64())
+            .unwrap_or_default();
+        self.gate = settings_map
+            .get(&format!("{}_gate", key))
+            .and_then(|v| v.get_string())
+            .unwrap_or_default();
+    }
+}
+
+impl Widget for SplitEntry {
+    type Args = <Split as Widget>::Args;
+
+    fn register(key: &str, description: &str, args: Self::Args) -> Self {
+        let split = Split::register(key, description, args);
+        let act = ActLimit::register(&format!("{}_act", key), "Act", Default::default());
+        let first_visit = bool::register(
+            &format!("{}_first_visit", key),
+            "Only on First Visit to a Room",
+            Default::default(),
+        );
+        let mut entry = SplitEntry {
+            split,
+            act,
+            first_visit,
+            ..Default::default()
+        };
+        entry.update_params(&asr::settings::Map::load(), key);
+        entry
+    }
+
+    fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, args: Self::Args) {
+        self.split.update_from(settings_map, key, args);
+        self.act
+            .update_from(settings_map, &format!("{}_act", key), Default::default());
+        self.first_visit.update_from(
+            settings_map,
+            &format!("{}_first_visit", key),
+            Default::default(),
+        );
+        self.update_params(settings_map, key);
+    }
+}
+
+impl StoreWidget for SplitEntry {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let a = self.split.insert_into(settings_map, key);
+        let b = insert_param(
+            settings_map,
+            &format!("{}_name", key),
+            self.name.as_str(),
+            self.name.is_empty(),
+            |v| v.get_string().is_some_and(|s| s == self.name),
+        );
+        let c = insert_param(
+            settings_map,
+            &format!("{}_amount", key),
+            self.amount,
+            self.amount == 0,
+            |v| v.get_i64() == Some(self.amount),
+        );
+        let d = insert_param(
+            settings_map,
+            &format!("{}_act", key),
+            self.act.key(),
+            self.act == ActLimit::AnyAct,
+            |v| v.get_string().is_some_and(|s| s == self.act.key()),
+        );
+        let e = insert_param(
+            settings_map,
+            &format!("{}_gate", key),
+            self.gate.as_str(),
+            self.gate.is_empty(),
+            |v| v.get_string().is_some_and(|s| s == self.gate),
+        );
+        let f = insert_param(
+            settings_map,
+            &format!("{}_first_visit", key),
+            self.first_visit,
+            !self.first_visit,
+            |v| v.get_bool() == Some(self.first_visit),
+        );
+        a || b || c || d || e || f
+    }
+}
+
+/// Inserts a parameter unless the settings already have it,
+/// leaving out a default parameter that was never set
+fn insert_param<T: asr::settings::AsValue>(
+    settings_map: &asr::settings::Map,
+    key: &str,
+    value: T,
+    is_default: bool,
+    same: impl Fn(&asr::settings::Value) -> bool,
+) -> bool {
+    match settings_map.get(key) {
+        Some(old) if same(&old) => false,
+        None if is_default => false,
+        _ => {
+            settings_map.insert(key, value);
+            true
+        }
+    }
+}
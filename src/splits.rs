@@ -7,14 +7,21 @@ use ugly_widget::{
 use utf16_lit::utf16;
 
 use crate::{
+    act::{get_act, Act},
+    collections::Collection,
+    endings::Ending,
     silksong_memory::{
-        get_at_bench, get_health, get_heart_pieces, get_is_maggoted, get_max_health_base,
-        get_respawn_scene, get_silk_max, get_silk_spool_parts, is_discontinuity_scene, is_menu,
-        Env, SceneStore, CINEMATIC_STAG_TRAVEL, DEATH_RESPAWN_MARKER_INIT, GAME_STATE_PLAYING,
-        MENU_TITLE, NON_MENU_GAME_STATES, OPENING_SCENES,
+        get_at_bench, get_boss_rush_mode, get_boss_sequence_index, get_bosses_defeated,
+        get_completed_endings, get_health, get_heart_pieces, get_is_maggoted, get_max_health_base,
+        get_respawn_scene, get_silk_max, get_silk_spool_parts, get_steel_quest_spots,
+        get_steel_quest_spots_seen, is_discontinuity_scene, is_menu, Env, SceneStore,
+        CINEMATIC_STAG_TRAVEL, DEATH_RESPAWN_MARKER_INIT, GAME_STATE_PLAYING, MENU_TITLE,
+        NON_MENU_GAME_STATES, OPENING_SCENES,
     },
+    split_entry::SplitEntry,
     store::Store,
     timer::{reached_up_to_split, should_split, SplitterAction},
+    transition::TransitionKind,
 };
 
 #[derive(Clone, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
@@ -44,6 +51,27 @@ pub enum Split {
     ///
     /// Splits on Weaver Queen ending
     EndingA,
+    /// Completed Any Ending (Ending)
+    ///
+    /// Splits when the save file records any newly completed ending,
+    /// even if the ending cutscene is skipped
+    CompletedEndingAny,
+    /// Completed Weaver Queen (Ending)
+    ///
+    /// Splits when the save file records the Weaver Queen ending as completed
+    CompletedWeaverQueen,
+    /// Completed Twisted Child (Ending)
+    ///
+    /// Splits when the save file records the Twisted Child ending as completed
+    CompletedTwistedChild,
+    /// Completed Snared Silk (Ending)
+    ///
+    /// Splits when the save file records the Snared Silk ending as completed
+    CompletedSnaredSilk,
+    /// Completed Sister of the Void (Ending)
+    ///
+    /// Splits when the save file records the Sister of the Void ending as completed
+    CompletedSisterOfTheVoid,
     /// Main Menu (Menu)
     ///
     /// Splits on the main menu
@@ -69,8 +97,90 @@ pub enum Split {
     /// Splits when entering a transition
     /// (excludes discontinuities including save states, deaths, and bellway travel)
     TransitionExcludingDiscontinuities,
+    /// Walking Transition (Transition)
+    ///
+    /// Splits on a transition from walking, jumping, or falling through a gate
+    WalkTransition,
+    /// Bellway Transition (Transition)
+    ///
+    /// Splits on a transition from riding the bellbeast to another station
+    BellwayTransition,
+    /// Ventrica Transition (Transition)
+    ///
+    /// Splits on a transition from riding a ventrica to another station
+    VentricaTransition,
+    /// Death Respawn Transition (Transition)
+    ///
+    /// Splits on a transition from respawning after dying
+    DeathRespawnTransition,
+    /// Memory Transition (Transition)
+    ///
+    /// Splits on a transition from entering or leaving a memory
+    MemoryTransition,
+    /// Walking Transition into Named Scene (Transition)
+    ///
+    /// Splits on a walking, jumping, or falling transition into the scene named with the split entry, such as Bone_05,
+    /// or within the last N transitions with the entry's amount set to N
+    WalkTransitionInto,
+    /// Bellway Transition into Named Scene (Transition)
+    ///
+    /// Splits on riding the bellbeast into the scene named with the split entry,
+    /// or within the last N transitions with the entry's amount set to N
+    BellwayTransitionInto,
+    /// Ventrica Transition into Named Scene (Transition)
+    ///
+    /// Splits on riding a ventrica into the scene named with the split entry,
+    /// or within the last N transitions with the entry's amount set to N
+    VentricaTransitionInto,
+    /// Death Respawn Transition into Named Scene (Transition)
+    ///
+    /// Splits on respawning after dying in the scene named with the split entry,
+    /// or within the last N transitions with the entry's amount set to N
+    DeathRespawnTransitionInto,
+    /// Memory Transition into Named Scene (Transition)
+    ///
+    /// Splits on entering or leaving a memory into the scene named with the split entry,
+    /// or within the last N transitions with the entry's amount set to N
+    MemoryTransitionInto,
+    /// Area Change (Transition)
+    ///
+    /// Splits when entering a different area
+    AreaChange,
+    /// Enter New Area (Transition)
+    ///
+    /// Splits when entering an area for the first time in the run
+    EnterNewArea,
+    /// Enter Named Area (Transition)
+    ///
+    /// Splits when entering the area named with the split entry from a different area, such as The Slab
+    EnterArea,
+    /// Enter Named Area for the First Time (Transition)
+    ///
+    /// Splits when entering the area named with the split entry for the first time in the run
+    EnterAreaFirstTime,
+    /// Enter New Room (Transition)
+    ///
+    /// Splits when entering a room for the first time in the run
+    EnterNewRoom,
     // endregion: Start, End, and Menu
 
+    // region: BossRush
+    /// Boss Rush Start (Start)
+    ///
+    /// Splits when a boss rush sequence begins
+    BossRushStart,
+    /// Boss Rush Boss (Boss Rush)
+    ///
+    /// Splits when defeating a boss during a boss rush sequence,
+    /// going by the sequence moving on to its next boss,
+    /// or by the boss defeat flags if the sequence can't be read
+    BossRushBoss,
+    /// Boss Rush End (End)
+    ///
+    /// Splits when a boss rush sequence is completed, but not when quitting out of it
+    BossRushEnd,
+    // endregion: BossRush
+
     // region: MossLands
     /// Moss Mother (Boss)
     ///
@@ -1056,6 +1166,211 @@ pub enum Split {
     CraftingKit4,
     // endregion: CraftingKitLevels
 
+    // region: Completion
+    /// 10% (Completion)
+    ///
+    /// Splits when the live completion percent reaches 10%
+    Percent10,
+    /// 20% (Completion)
+    ///
+    /// Splits when the live completion percent reaches 20%
+    Percent20,
+    /// 30% (Completion)
+    ///
+    /// Splits when the live completion percent reaches 30%
+    Percent30,
+    /// 40% (Completion)
+    ///
+    /// Splits when the live completion percent reaches 40%
+    Percent40,
+    /// 50% (Completion)
+    ///
+    /// Splits when the live completion percent reaches 50%
+    Percent50,
+    /// 60% (Completion)
+    ///
+    /// Splits when the live completion percent reaches 60%
+    Percent60,
+    /// 70% (Completion)
+    ///
+    /// Splits when the live completion percent reaches 70%
+    Percent70,
+    /// 80% (Completion)
+    ///
+    /// Splits when the live completion percent reaches 80%
+    Percent80,
+    /// 90% (Completion)
+    ///
+    /// Splits when the live completion percent reaches 90%
+    Percent90,
+    /// 100% (Completion)
+    ///
+    /// Splits when the live completion percent reaches 100%
+    Percent100,
+    // endregion: Completion
+
+    // region: Currency
+    /// 100 Rosaries (Currency)
+    ///
+    /// Splits when holding at least 100 rosaries
+    Rosaries100,
+    /// 300 Rosaries (Currency)
+    ///
+    /// Splits when holding at least 300 rosaries
+    Rosaries300,
+    /// 500 Rosaries (Currency)
+    ///
+    /// Splits when holding at least 500 rosaries
+    Rosaries500,
+    /// 1000 Rosaries (Currency)
+    ///
+    /// Splits when holding at least 1000 rosaries
+    Rosaries1000,
+    /// 2000 Rosaries (Currency)
+    ///
+    /// Splits when holding at least 2000 rosaries
+    Rosaries2000,
+    /// Spent Rosaries in Scene (Currency)
+    ///
+    /// Splits when the split's amount of rosaries have been spent in the split's scene during the run,
+    /// or any rosaries when the amount is 0.
+    /// Set the scene with `splits_{i}_item_name`, such as `Bonetown` for the Bone Bottom shop,
+    /// and the amount with `splits_{i}_item_amount`
+    SpentInScene,
+    // endregion: Currency
+
+    // region: Quests
+    /// Accepted Quest (Quest)
+    ///
+    /// Splits when accepting any quest
+    QuestAcceptedAny,
+    /// Completed Quest (Quest)
+    ///
+    /// Splits when completing any quest
+    QuestCompletedAny,
+    /// Accepted Named Quest (Quest)
+    ///
+    /// Splits when accepting the quest named by `splits_{i}_item_name`,
+    /// the name it is saved under in QuestCompletionData
+    QuestAccepted,
+    /// Completed Named Quest (Quest)
+    ///
+    /// Splits when completing the quest named by `splits_{i}_item_name`,
+    /// the name it is saved under in QuestCompletionData
+    QuestCompleted,
+    /// 5 Quests Completed (Quest)
+    ///
+    /// Splits when 5 quests have been completed, or skips if more already have
+    Quests5,
+    /// 10 Quests Completed (Quest)
+    ///
+    /// Splits when 10 quests have been completed, or skips if more already have
+    Quests10,
+    /// 15 Quests Completed (Quest)
+    ///
+    /// Splits when 15 quests have been completed, or skips if more already have
+    Quests15,
+    /// 20 Quests Completed (Quest)
+    ///
+    /// Splits when 20 quests have been completed, or skips if more already have
+    Quests20,
+    /// 25 Quests Completed (Quest)
+    ///
+    /// Splits when 25 quests have been completed, or skips if more already have
+    Quests25,
+    // endregion: Quests
+
+    // region: Journal
+    /// 10 Journal Entries Encountered (Journal)
+    ///
+    /// Splits when 10 Hunter's Journal entries have been encountered, with at least one kill,
+    /// or skips if more already have
+    JournalEncountered10,
+    /// 50 Journal Entries Encountered (Journal)
+    ///
+    /// Splits when 50 Hunter's Journal entries have been encountered, with at least one kill,
+    /// or skips if more already have
+    JournalEncountered50,
+    /// 100 Journal Entries Encountered (Journal)
+    ///
+    /// Splits when 100 Hunter's Journal entries have been encountered, with at least one kill,
+    /// or skips if more already have
+    JournalEncountered100,
+    /// 150 Journal Entries Encountered (Journal)
+    ///
+    /// Splits when 150 Hunter's Journal entries have been encountered, with at least one kill,
+    /// or skips if more already have
+    JournalEncountered150,
+    /// Killed Named Enemy (Journal)
+    ///
+    /// Splits when the Hunter's Journal counts the split's amount of kills, or at least one kill when the amount is 0,
+    /// of the enemy named by `splits_{i}_item_name`, the name it is saved under in EnemyJournalKillData
+    EnemyKilled,
+    // endregion: Journal
+
+    // region: Collectables
+    /// Collected Item (Collectable)
+    ///
+    /// Splits when collecting a kind of collectable item that was not held before
+    CollectableAny,
+    /// Collected Named Item (Collectable)
+    ///
+    /// Splits when holding the split's amount, or at least one when the amount is 0,
+    /// of the collectable item named by `splits_{i}_item_name`, the name it is saved under in Collectables
+    Collectable,
+    /// Collected Relic (Relic)
+    ///
+    /// Splits when collecting any relic
+    RelicCollectedAny,
+    /// Deposited Relic (Relic)
+    ///
+    /// Splits when depositing any relic
+    RelicDepositedAny,
+    /// Collected Named Relic (Relic)
+    ///
+    /// Splits when collecting the relic named by `splits_{i}_item_name`, the name it is saved under in Relics
+    RelicCollected,
+    /// Deposited Named Relic (Relic)
+    ///
+    /// Splits when depositing the relic named by `splits_{i}_item_name`, the name it is saved under in Relics
+    RelicDeposited,
+    /// 5 Relics Deposited (Relic)
+    ///
+    /// Splits when 5 relics have been deposited, or skips if more already have
+    RelicsDeposited5,
+    /// 10 Relics Deposited (Relic)
+    ///
+    /// Splits when 10 relics have been deposited, or skips if more already have
+    RelicsDeposited10,
+    /// 15 Relics Deposited (Relic)
+    ///
+    /// Splits when 15 relics have been deposited, or skips if more already have
+    RelicsDeposited15,
+    /// 20 Relics Deposited (Relic)
+    ///
+    /// Splits when 20 relics have been deposited, or skips if more already have
+    RelicsDeposited20,
+    /// Deposited Memento (Memento)
+    ///
+    /// Splits when depositing any memento
+    MementoDepositedAny,
+    /// Deposited Named Memento (Memento)
+    ///
+    /// Splits when depositing the memento named by `splits_{i}_item_name`, the name it is saved under in MementosDeposited
+    MementoDeposited,
+    // endregion: Collectables
+
+    // region: SteelSoul
+    /// Steel Soul Quest Spot (Steel Soul)
+    ///
+    /// Splits when seeing any Steel Soul quest spot
+    SteelQuestSpotAny,
+    /// All Steel Soul Quest Spots (Steel Soul)
+    ///
+    /// Splits when every Steel Soul quest spot has been seen
+    SteelQuestSpotsAll,
+    // endregion: SteelSoul
+
     // region: Crests
     /// Reaper Crest (Crest)
     ///
@@ -1137,6 +1452,38 @@ pub enum Split {
     ///
     /// Splits when leaving the room after obtaining Sylphsong
     SylphsongTrans,
+    /// Unlocked Crest (Crest)
+    ///
+    /// Splits when any crest is unlocked
+    CrestUnlockedAny,
+    /// Unlocked Named Crest (Crest)
+    ///
+    /// Splits when the crest with the id in `splits_{i}_item_name` is unlocked.
+    /// The ids are `Hunter`, `Reaper`, `Wanderer`, `Warrior` for Beast, `Witch`,
+    /// `Toolmaster` for Architect, `Spell` for Shaman, and `Cursed`
+    CrestUnlocked,
+    /// Equip Named Crest (Crest)
+    ///
+    /// Splits when swapping to the crest with the id in `splits_{i}_item_name`.
+    /// The ids are the same as for Unlocked Named Crest,
+    /// plus `Hunter_v2` and `Hunter_v3` for the evolved Hunter Crest
+    EquipCrest,
+    /// Swapped Crest (Crest)
+    ///
+    /// Splits when swapping to any other crest
+    CrestSwapAny,
+    /// 5 Crest Swaps (Crest)
+    ///
+    /// Splits when swapping crests for the 5th time in the run, or skips if already swapped more
+    CrestSwaps5,
+    /// 10 Crest Swaps (Crest)
+    ///
+    /// Splits when swapping crests for the 10th time in the run, or skips if already swapped more
+    CrestSwaps10,
+    /// 25 Crest Swaps (Crest)
+    ///
+    /// Splits when swapping crests for the 25th time in the run, or skips if already swapped more
+    CrestSwaps25,
     // endregion: Crests
 
     // region: FleaSpecific
@@ -1261,6 +1608,30 @@ pub enum Split {
     ///
     /// Splits after freeing Kratt
     SavedFleaKratt,
+    /// 5 Fleas (Flea)
+    ///
+    /// Splits when 5 fleas have been rescued, or skips if more already have
+    Fleas5,
+    /// 10 Fleas (Flea)
+    ///
+    /// Splits when 10 fleas have been rescued, or skips if more already have
+    Fleas10,
+    /// 15 Fleas (Flea)
+    ///
+    /// Splits when 15 fleas have been rescued, or skips if more already have
+    Fleas15,
+    /// 20 Fleas (Flea)
+    ///
+    /// Splits when 20 fleas have been rescued, or skips if more already have
+    Fleas20,
+    /// 25 Fleas (Flea)
+    ///
+    /// Splits when 25 fleas have been rescued, or skips if more already have
+    Fleas25,
+    /// All Fleas (Flea)
+    ///
+    /// Splits when all 30 fleas have been rescued
+    FleasAll,
     // endregion: FleaSpecific
 
     // region: Bellways
@@ -1309,6 +1680,14 @@ pub enum Split {
     ///
     /// Splits when riding the Bell Beast through the Bellways
     BellwayTrans,
+    /// 5 Bellways (Bellway)
+    ///
+    /// Splits when 5 bellways have been unlocked, or skips if more already have
+    Bellways5,
+    /// All Bellways (Bellway)
+    ///
+    /// Splits when all 10 bellways have been unlocked
+    BellwaysAll,
     // endregion: Bellways
 
     // region: Ventricas
@@ -1341,6 +1720,14 @@ pub enum Split {
     ///
     /// Splits when traveling to another room using the Ventrica system
     VentricaTrans,
+    /// 3 Ventricas (Ventrica)
+    ///
+    /// Splits when 3 ventricas have been unlocked, or skips if more already have
+    Ventricas3,
+    /// All Ventricas (Ventrica)
+    ///
+    /// Splits when all 6 ventricas have been unlocked
+    VentricasAll,
     // endregion: Ventricas
 
     // region: ShakraEncounters
@@ -1404,6 +1791,14 @@ pub enum Split {
     ///
     /// Splits after purchasing all of Shakra's maps
     ShakraMapBuyout,
+    /// 7 Maps (Map)
+    ///
+    /// Splits when 7 maps have been bought, or skips if more already have
+    Maps7,
+    /// All Maps (Map)
+    ///
+    /// Splits when all 14 maps have been bought
+    MapsAll,
     // endregion: ShakraEncounters
 
     // region: MiscTE
@@ -1544,6 +1939,14 @@ pub enum Split {
     ///
     /// Splits when you reach the required number of Verdania Orbs
     VerdaniaOrbsCollected,
+    /// 6 Memory Orbs (Event)
+    ///
+    /// Splits when 6 memory orbs have been collected, or skips if more already have
+    MemoryOrbs6,
+    /// All Memory Orbs (Event)
+    ///
+    /// Splits when all 12 memory orbs have been collected
+    MemoryOrbsAll,
     /// Forebrothers (Boss)
     ///
     /// Splits after defeating the Forebrothers
@@ -1980,7 +2383,7 @@ pub fn transition_splits(
         // region: Start, End, and Menu
         Split::StartNewGame => should_split(
             (OPENING_SCENES.contains(&scenes.old)
-                || OPENING_SCENES.contains(&ss.prev_scene_name.as_str()))
+                || ss.history().entered_before_latest(&OPENING_SCENES, 2))
                 && scenes.current == "Tut_01",
         ),
         Split::EndingSplit => should_split(scenes.current.starts_with("Cinematic_Ending")),
@@ -1997,6 +2400,24 @@ pub fn transition_splits(
                     .read_string(&gm.entry_gate_name)
                     .is_some_and(|e| e == "dreamGate")),
         ),
+        Split::WalkTransition => {
+            should_split(!split_this_transition && ss.transition_kind() == TransitionKind::Walk)
+        }
+        Split::BellwayTransition => {
+            should_split(!split_this_transition && ss.transition_kind() == TransitionKind::Bellway)
+        }
+        Split::VentricaTransition => {
+            should_split(!split_this_transition && ss.transition_kind() == TransitionKind::Ventrica)
+        }
+        Split::DeathRespawnTransition => should_split(
+            !split_this_transition && ss.transition_kind() == TransitionKind::DeathRespawn,
+        ),
+        Split::MemoryTransition => {
+            should_split(!split_this_transition && ss.transition_kind() == TransitionKind::Memory)
+        }
+        Split::AreaChange => should_split(store.get_area().changed()),
+        Split::EnterNewArea => should_split(store.get_area().entered_new()),
+        Split::EnterNewRoom => should_split(store.get_area().entered_new_scene()),
         // endregion: Start, End, and Menu
 
         // region: MossLands
@@ -2504,8 +2925,9 @@ pub fn transition_once_splits(split: &Split, ss: &SceneStore, e: &Env) -> Option
         Split::Act1Start => should_split(
             scenes.current == "Tut_01"
                 && (OPENING_SCENES.contains(&scenes.old)
-                    || OPENING_SCENES.contains(&ss.prev_scene_name.as_str())
-                    || ((scenes.old == MENU_TITLE || ss.prev_scene_name == MENU_TITLE)
+                    || ss.history().entered_before_latest(&OPENING_SCENES, 2)
+                    || ((scenes.old == MENU_TITLE
+                        || ss.history().entered_before_latest(&[MENU_TITLE], 2))
                         && mem.read_string(&gm.entry_gate_name).unwrap_or_default()
                             == DEATH_RESPAWN_MARKER_INIT))
                 && mem.deref(&pd.disable_pause).is_ok_and(|d: bool| !d)
@@ -2545,6 +2967,31 @@ fn spool_shard_split(e: &Env, shard: i32) -> bool {
             .is_ok_and(|n: i32| n == current_shards))
 }
 
+fn quests_completed_split(store: &mut Store, n: i32) -> Option<SplitterAction> {
+    let completed = store.get_quest_counts_pair()?.current.completed;
+    reached_up_to_split(n, Ok(completed))
+}
+
+fn journal_encountered_split(store: &mut Store, n: i32) -> Option<SplitterAction> {
+    let encountered = store.get_journal_counts_pair()?.current.encountered;
+    reached_up_to_split(n, Ok(encountered))
+}
+
+fn relics_deposited_split(store: &mut Store, n: i32) -> Option<SplitterAction> {
+    let deposited = store.get_relic_counts_pair()?.current.deposited;
+    reached_up_to_split(n, Ok(deposited))
+}
+
+/// Whether the ending, or any ending if None, was newly added to CompletedEndings
+fn ending_split(store: &mut Store, e: &Env, ending: Option<Ending>) -> bool {
+    store
+        .get_i32_pair_bang("completed_endings", &get_completed_endings, Some(e))
+        .is_some_and(|p| {
+            let added = p.current & !p.old;
+            ending.map_or(added != 0, |ending| ending.is_in(added))
+        })
+}
+
 fn bench_split(store: &mut Store, e: &Env) -> bool {
     store
         .get_bool_pair_bang("at_bench", &get_at_bench, Some(e))
@@ -2572,8 +3019,52 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
                 .is_some_and(|p| p.changed_to(&false))
                 && mem.deref(&pd.health).is_ok_and(|h: i32| h > 0),
         ),
+        Split::CompletedEndingAny => should_split(ending_split(store, e, None)),
+        Split::CompletedWeaverQueen => {
+            should_split(ending_split(store, e, Some(Ending::WeaverQueen)))
+        }
+        Split::CompletedTwistedChild => {
+            should_split(ending_split(store, e, Some(Ending::TwistedChild)))
+        }
+        Split::CompletedSnaredSilk => {
+            should_split(ending_split(store, e, Some(Ending::SnaredSilk)))
+        }
+        Split::CompletedSisterOfTheVoid => {
+            should_split(ending_split(store, e, Some(Ending::SisterOfTheVoid)))
+        }
         // endregion: Start, End, and Menu
 
+        // region: BossRush
+        Split::BossRushStart => should_split(
+            store
+                .get_bool_pair_bang("boss_rush_mode", &get_boss_rush_mode, Some(e))
+                .is_some_and(|p| p.changed_to(&true)),
+        ),
+        Split::BossRushBoss => should_split(
+            mem.deref(&pd.boss_rush_mode).unwrap_or_default()
+                && match store.get_i32_pair_bang(
+                    "boss_sequence_index",
+                    &get_boss_sequence_index,
+                    Some(e),
+                ) {
+                    Some(p) => p.old < p.current,
+                    // bosses beaten before the rush already have their flags set,
+                    // so this only catches the ones beaten for the first time
+                    None => store
+                        .get_i32_pair_bang("bosses_defeated", &get_bosses_defeated, Some(e))
+                        .is_some_and(|p| p.old < p.current),
+                },
+        ),
+        Split::BossRushEnd => should_split(
+            store
+                .get_bool_pair_bang("boss_rush_mode", &get_boss_rush_mode, Some(e))
+                .is_some_and(|p| p.changed_to(&false))
+                // quitting out leaves the sequence from a menu game state
+                && game_state == GAME_STATE_PLAYING
+                && mem.deref(&pd.health).is_ok_and(|h: i32| h > 0),
+        ),
+        // endregion: BossRush
+
         // region: MossLands
         Split::MossMother => should_split(mem.deref(&pd.defeated_moss_mother).unwrap_or_default()),
         Split::SilkSpear => should_split(mem.deref(&pd.has_needle_throw).unwrap_or_default()),
@@ -2734,7 +3225,7 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
         // endregion: SandsOfKarak
 
         // region: Acts
-        Split::Act2Started => should_split(mem.deref(&pd.act2_started).unwrap_or_default()),
+        Split::Act2Started => should_split(get_act(e).is_ok_and(|act| Act::Act2 <= act)),
         // endregion: Acts
 
         // region: CogworkCore
@@ -2945,6 +3436,94 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
         Split::CraftingKit4 => reached_up_to_split(4, mem.deref(&pd.tool_kit_upgrades)),
         // endregion: CraftingKitLevels
 
+        // region: Completion
+        Split::Percent10 => should_split(store.get_completion(e).percent() >= 10),
+        Split::Percent20 => should_split(store.get_completion(e).percent() >= 20),
+        Split::Percent30 => should_split(store.get_completion(e).percent() >= 30),
+        Split::Percent40 => should_split(store.get_completion(e).percent() >= 40),
+        Split::Percent50 => should_split(store.get_completion(e).percent() >= 50),
+        Split::Percent60 => should_split(store.get_completion(e).percent() >= 60),
+        Split::Percent70 => should_split(store.get_completion(e).percent() >= 70),
+        Split::Percent80 => should_split(store.get_completion(e).percent() >= 80),
+        Split::Percent90 => should_split(store.get_completion(e).percent() >= 90),
+        Split::Percent100 => should_split(store.get_completion(e).percent() >= 100),
+        // endregion: Completion
+
+        // region: Currency
+        Split::Rosaries100 => should_split(mem.deref(&pd.geo).is_ok_and(|g: i32| g >= 100)),
+        Split::Rosaries300 => should_split(mem.deref(&pd.geo).is_ok_and(|g: i32| g >= 300)),
+        Split::Rosaries500 => should_split(mem.deref(&pd.geo).is_ok_and(|g: i32| g >= 500)),
+        Split::Rosaries1000 => should_split(mem.deref(&pd.geo).is_ok_and(|g: i32| g >= 1000)),
+        Split::Rosaries2000 => should_split(mem.deref(&pd.geo).is_ok_and(|g: i32| g >= 2000)),
+        // endregion: Currency
+
+        // region: Quests
+        Split::QuestAcceptedAny => should_split(
+            store
+                .get_quest_counts_pair()
+                .is_some_and(|p| p.old.accepted < p.current.accepted),
+        ),
+        Split::QuestCompletedAny => should_split(
+            store
+                .get_quest_counts_pair()
+                .is_some_and(|p| p.old.completed < p.current.completed),
+        ),
+        Split::Quests5 => quests_completed_split(store, 5),
+        Split::Quests10 => quests_completed_split(store, 10),
+        Split::Quests15 => quests_completed_split(store, 15),
+        Split::Quests20 => quests_completed_split(store, 20),
+        Split::Quests25 => quests_completed_split(store, 25),
+        // endregion: Quests
+
+        // region: Journal
+        Split::JournalEncountered10 => journal_encountered_split(store, 10),
+        Split::JournalEncountered50 => journal_encountered_split(store, 50),
+        Split::JournalEncountered100 => journal_encountered_split(store, 100),
+        Split::JournalEncountered150 => journal_encountered_split(store, 150),
+        // endregion: Journal
+
+        // region: Collectables
+        Split::CollectableAny => should_split(
+            store
+                .get_collectables_count_pair()
+                .is_some_and(|p| p.old < p.current),
+        ),
+        Split::RelicCollectedAny => should_split(
+            store
+                .get_relic_counts_pair()
+                .is_some_and(|p| p.old.collected < p.current.collected),
+        ),
+        Split::RelicDepositedAny => should_split(
+            store
+                .get_relic_counts_pair()
+                .is_some_and(|p| p.old.deposited < p.current.deposited),
+        ),
+        Split::RelicsDeposited5 => relics_deposited_split(store, 5),
+        Split::RelicsDeposited10 => relics_deposited_split(store, 10),
+        Split::RelicsDeposited15 => relics_deposited_split(store, 15),
+        Split::RelicsDeposited20 => relics_deposited_split(store, 20),
+        Split::MementoDepositedAny => should_split(
+            store
+                .get_mementos_count_pair()
+                .is_some_and(|p| p.old < p.current),
+        ),
+        // endregion: Collectables
+
+        // region: SteelSoul
+        Split::SteelQuestSpotAny => should_split(
+            store
+                .get_i32_pair_bang(
+                    "steel_quest_spots_seen",
+                    &get_steel_quest_spots_seen,
+                    Some(e),
+                )
+                .is_some_and(|p| p.old < p.current),
+        ),
+        Split::SteelQuestSpotsAll => should_split(
+            get_steel_quest_spots(Some(e)).is_some_and(|(seen, total)| 0 < total && seen == total),
+        ),
+        // endregion: SteelSoul
+
         // region: Crests
         Split::ReaperCrest => {
             should_split(mem.deref(&pd.completed_memory_reaper).unwrap_or_default())
@@ -2988,6 +3567,15 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
         Split::Sylphsong => {
             should_split(mem.deref(&pd.has_bound_crest_upgrader).unwrap_or_default())
         }
+        Split::CrestUnlockedAny => should_split(
+            store
+                .get_crests_unlocked_pair()
+                .is_some_and(|p| p.old < p.current),
+        ),
+        Split::CrestSwapAny => should_split(store.get_crest().changed()),
+        Split::CrestSwaps5 => reached_up_to_split(5, Ok(store.get_crest().swaps())),
+        Split::CrestSwaps10 => reached_up_to_split(10, Ok(store.get_crest().swaps())),
+        Split::CrestSwaps25 => reached_up_to_split(25, Ok(store.get_crest().swaps())),
         // endregion: Crests
 
         // region: FleaSpecific
@@ -3082,6 +3670,12 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
         Split::SavedFleaKratt => {
             should_split(mem.deref(&pd.caravan_lech_saved).unwrap_or_default())
         }
+        Split::Fleas5 => reached_up_to_split(5, Collection::Fleas.count(e)),
+        Split::Fleas10 => reached_up_to_split(10, Collection::Fleas.count(e)),
+        Split::Fleas15 => reached_up_to_split(15, Collection::Fleas.count(e)),
+        Split::Fleas20 => reached_up_to_split(20, Collection::Fleas.count(e)),
+        Split::Fleas25 => reached_up_to_split(25, Collection::Fleas.count(e)),
+        Split::FleasAll => reached_up_to_split(30, Collection::Fleas.count(e)),
         // endregion: FleaSpecific
 
         // region: Stations (Bellway)
@@ -3118,6 +3712,8 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
             mem.deref(&pd.unlocked_shellwood_station)
                 .unwrap_or_default(),
         ),
+        Split::Bellways5 => reached_up_to_split(5, Collection::Bellways.count(e)),
+        Split::BellwaysAll => reached_up_to_split(10, Collection::Bellways.count(e)),
         // endregion: Stations (Bellway)
 
         // region: Ventricas
@@ -3138,6 +3734,8 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
         Split::MemoriumTube => {
             should_split(mem.deref(&pd.unlocked_arborium_tube).unwrap_or_default())
         }
+        Split::Ventricas3 => reached_up_to_split(3, Collection::Ventricas.count(e)),
+        Split::VentricasAll => reached_up_to_split(6, Collection::Ventricas.count(e)),
         // endregion: Ventricas
 
         // region: ShakraEncounters
@@ -3203,6 +3801,8 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
             ];
             should_split(maps.iter().all(|map| mem.deref(map).unwrap_or_default()))
         }
+        Split::Maps7 => reached_up_to_split(7, Collection::Maps.count(e)),
+        Split::MapsAll => reached_up_to_split(14, Collection::Maps.count(e)),
         // endregion: ShakraEncounters
 
         // region: MiscTE
@@ -3271,32 +3871,13 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
         Split::VerdaniaLakeFountainOrbs => {
             should_split(mem.deref(&pd.summoned_lake_orbs).unwrap_or_default())
         }
-        Split::VerdaniaOrbsCollected => {
-            let orb_02c: bool = mem.deref(&pd.orbs_02c).unwrap_or_default();
-            let orb_03: bool = mem.deref(&pd.orbs_03).unwrap_or_default();
-            let orb_06: bool = mem.deref(&pd.orbs_06).unwrap_or_default();
-            let orb_11: bool = mem.deref(&pd.orbs_11).unwrap_or_default();
-            let orb_16_b: bool = mem.deref(&pd.orbs_16_b).unwrap_or_default();
-            let orb_16_c: bool = mem.deref(&pd.orbs_16_c).unwrap_or_default();
-            let orb_21: bool = mem.deref(&pd.orbs_21).unwrap_or_default();
-            // C# ulong is an unsigned 64-bit int
-            let orb_18_a: u64 = mem.deref(&pd.orbs_18_a).unwrap_or_default();
-            let orb_18_b: u64 = mem.deref(&pd.orbs_18_b).unwrap_or_default();
-            let orb_18_c: u64 = mem.deref(&pd.orbs_18_c).unwrap_or_default();
-            let orb_18_d: u64 = mem.deref(&pd.orbs_18_d).unwrap_or_default();
-            let orb_18_e: u64 = mem.deref(&pd.orbs_18_e).unwrap_or_default();
-            let orb_19: u64 = mem.deref(&pd.orbs_19).unwrap_or_default();
-
-            let singles: usize = [orb_02c, orb_03, orb_06, orb_11, orb_16_b, orb_16_c, orb_21]
-                .into_iter()
-                .filter(|b| *b)
-                .count();
-            let multis: usize = [orb_18_a, orb_18_b, orb_18_c, orb_18_d, orb_18_e, orb_19]
-                .into_iter()
-                .fold(0, |acc, x| acc + (x.count_ones() as usize));
-            let total = singles + multis;
-            should_split(total >= 12)
-        }
+        Split::VerdaniaOrbsCollected => should_split(
+            Collection::MemoryOrbs
+                .count(e)
+                .is_ok_and(|n| n >= Collection::MemoryOrbs.total()),
+        ),
+        Split::MemoryOrbs6 => reached_up_to_split(6, Collection::MemoryOrbs.count(e)),
+        Split::MemoryOrbsAll => reached_up_to_split(12, Collection::MemoryOrbs.count(e)),
         Split::Forebrothers => {
             should_split(mem.deref(&pd.defeated_dock_foremen).unwrap_or_default())
         }
@@ -3355,7 +3936,7 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
             mem.deref(&pd.ballow_moved_to_diving_bell)
                 .unwrap_or_default(),
         ),
-        Split::Act3Started => should_split(mem.deref(&pd.black_thread_world).unwrap_or_default()),
+        Split::Act3Started => should_split(get_act(e).is_ok_and(|act| act == Act::Act3)),
         // endregion: MiscTE
 
         // region: Abyss
@@ -3434,32 +4015,158 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> Option<Sp
     }
 }
 
+/// Whether a transition is happening now, and the scene named with the entry
+/// was entered by a transition of the kind within the last N transitions,
+/// where N is the entry's amount, or 1 when it is unset
+fn transition_into(
+    entry: &SplitEntry,
+    trans_now: bool,
+    ss: &SceneStore,
+    kind: TransitionKind,
+) -> bool {
+    trans_now
+        && !ss.split_this_transition
+        && ss.history().entered_within(
+            &[entry.name.as_str()],
+            entry.amount.max(1) as usize,
+            Some(kind),
+        )
+}
+
+/// Splits that read the name or amount of their entry
+fn entry_splits(
+    entry: &SplitEntry,
+    e: &Env,
+    trans_now: bool,
+    ss: &SceneStore,
+    store: &mut Store,
+) -> Option<SplitterAction> {
+    match entry.split {
+        // region: Start, End, and Menu
+        Split::WalkTransitionInto => {
+            should_split(transition_into(entry, trans_now, ss, TransitionKind::Walk))
+        }
+        Split::BellwayTransitionInto => should_split(transition_into(
+            entry,
+            trans_now,
+            ss,
+            TransitionKind::Bellway,
+        )),
+        Split::VentricaTransitionInto => should_split(transition_into(
+            entry,
+            trans_now,
+            ss,
+            TransitionKind::Ventrica,
+        )),
+        Split::DeathRespawnTransitionInto => should_split(transition_into(
+            entry,
+            trans_now,
+            ss,
+            TransitionKind::DeathRespawn,
+        )),
+        Split::MemoryTransitionInto => should_split(transition_into(
+            entry,
+            trans_now,
+            ss,
+            TransitionKind::Memory,
+        )),
+        Split::EnterArea => should_split(store.get_area().entered(&entry.name, false)),
+        Split::EnterAreaFirstTime => should_split(store.get_area().entered(&entry.name, true)),
+        // endregion: Start, End, and Menu
+
+        // region: Currency
+        Split::SpentInScene => {
+            should_split(i64::from(store.get_spent(&entry.name)) >= entry.amount.max(1))
+        }
+        // endregion: Currency
+
+        // region: Quests
+        Split::QuestAccepted => should_split(
+            store
+                .get_quest(&entry.name_utf16, e)
+                .is_some_and(|quest| quest.is_accepted),
+        ),
+        Split::QuestCompleted => should_split(
+            store
+                .get_quest(&entry.name_utf16, e)
+                .is_some_and(|quest| quest.is_done()),
+        ),
+        // endregion: Quests
+
+        // region: Journal
+        Split::EnemyKilled => should_split(
+            store
+                .get_kill_data(&entry.name_utf16, e)
+                .is_some_and(|kill_data| i64::from(kill_data.kills) >= entry.amount.max(1)),
+        ),
+        // endregion: Journal
+
+        // region: Collectables
+        Split::Collectable => should_split(
+            store
+                .get_collectable(&entry.name_utf16, e)
+                .is_some_and(|collectable| i64::from(collectable.amount) >= entry.amount.max(1)),
+        ),
+        Split::RelicCollected => should_split(
+            store
+                .get_relic(&entry.name_utf16, e)
+                .is_some_and(|relic| relic.is_collected),
+        ),
+        Split::RelicDeposited => should_split(
+            store
+                .get_relic(&entry.name_utf16, e)
+                .is_some_and(|relic| relic.is_deposited),
+        ),
+        Split::MementoDeposited => should_split(
+            store
+                .get_memento(&entry.name_utf16, e)
+                .is_some_and(|memento| memento.is_deposited),
+        ),
+        // endregion: Collectables
+
+        // region: Crests
+        Split::CrestUnlocked => should_split(
+            store
+                .get_crest_data(&entry.name_utf16, e)
+                .is_some_and(|crest| crest.is_unlocked),
+        ),
+        Split::EquipCrest => should_split(store.get_crest().changed_to(&entry.name)),
+        // endregion: Crests
+
+        // else
+        _ => should_split(false),
+    }
+}
+
 pub fn splits(
-    split: &Split,
+    entry: &SplitEntry,
     env: &Env,
     trans_now: bool,
     ss: &mut SceneStore,
     store: &mut Store,
 ) -> Option<SplitterAction> {
-    let a1 = continuous_splits(split, env, store).or_else(|| {
-        let scenes = ss.pair();
-        let a2 = if !ss.split_this_transition {
-            transition_once_splits(split, ss, env)
-        } else {
-            None
-        };
-        a2.or_else(|| {
-            if trans_now {
-                if is_menu(scenes.old) || is_menu(scenes.current) {
-                    menu_splits(split, &scenes, env, store)
-                } else {
-                    transition_splits(split, ss, env, store, ss.split_this_transition)
-                }
+    let split = &entry.split;
+    let a1 = entry_splits(entry, env, trans_now, ss, store)
+        .or_else(|| continuous_splits(split, env, store))
+        .or_else(|| {
+            let scenes = ss.pair();
+            let a2 = if !ss.split_this_transition {
+                transition_once_splits(split, ss, env)
             } else {
                 None
-            }
-        })
-    });
+            };
+            a2.or_else(|| {
+                if trans_now {
+                    if is_menu(scenes.old) || is_menu(scenes.current) {
+                        menu_splits(split, &scenes, env, store)
+                    } else {
+                        transition_splits(split, ss, env, store, ss.split_this_transition)
+                    }
+                } else {
+                    None
+                }
+            })
+        });
     if a1.is_some() {
         ss.split_this_transition = true;
     }
@@ -0,0 +1,42 @@
+use asr::settings::Gui;
+
+use crate::silksong_memory::{PERMADEATH_MODE_DEAD, PERMADEATH_MODE_OFF, PERMADEATH_MODE_ON};
+
+/// Which save files the run may auto-start on
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, PartialEq)]
+pub enum FileMode {
+    /// Any
+    #[default]
+    Any,
+    /// Normal
+    Normal,
+    /// Steel Soul
+    SteelSoul,
+}
+
+impl FileMode {
+    /// Whether a file with the permadeath mode can start the run,
+    /// allowing it when the mode could not be read
+    pub fn allows(self, permadeath_mode: Option<i32>) -> bool {
+        let Some(mode) = permadeath_mode else {
+            return true;
+        };
+        match self {
+            FileMode::Any => true,
+            FileMode::Normal => mode == PERMADEATH_MODE_OFF,
+            FileMode::SteelSoul => mode == PERMADEATH_MODE_ON || mode == PERMADEATH_MODE_DEAD,
+        }
+    }
+}
+
+/// What to do when a Steel Soul character permanently dies
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, PartialEq)]
+pub enum SteelSoulDeath {
+    /// Nothing
+    #[default]
+    Nothing,
+    /// End the run
+    End,
+    /// Reset the run
+    Reset,
+}
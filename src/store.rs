@@ -1,6 +1,7 @@
 use alloc::{
     collections::BTreeMap,
     string::{String, ToString},
+    vec::Vec,
 };
 use asr::{
     timer::TimerState,
@@ -9,7 +10,17 @@ use asr::{
 
 #[cfg(feature = "split-index")]
 use crate::silksong_memory::get_timer_current_split_index;
-use crate::silksong_memory::{find_tool, get_timer_state, get_tools_version, read_tool, Env};
+use crate::{
+    area::AreaTracker,
+    completion::Completion,
+    crests::CrestTracker,
+    currency::Spending,
+    silksong_memory::{
+        get_timer_state, CollectableData, CrestData, Env, KillData, MementoData, NamedList,
+        NamedValue, QuestCompletion, RelicData, ToolData, COLLECTABLES_LIST, CRESTS_LIST,
+        JOURNAL_LIST, MEMENTOS_LIST, QUESTS_LIST, RELICS_LIST, TOOLS_LIST,
+    },
+};
 
 struct StoreValue<A: 'static> {
     watcher: Watcher<A>,
@@ -40,20 +51,53 @@ impl<A: Clone + Eq> StoreValue<A> {
     }
 }
 
-pub struct ToolCache {
+/// The number of quests accepted and completed
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct QuestCounts {
+    pub accepted: i32,
+    pub completed: i32,
+}
+
+/// The number of journal entries seen, and encountered with at least one kill
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct JournalCounts {
+    pub seen: i32,
+    pub encountered: i32,
+}
+
+/// The number of relics collected and deposited
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RelicCounts {
+    pub collected: i32,
+    pub deposited: i32,
+}
+
+/// Caches the index of one named value in a `NamedList`,
+/// and counts over all of its values, until the list's version changes
+pub struct NamedListCache<T: 'static, C: 'static> {
+    list: &'static NamedList,
     version: Option<i32>,
-    tool: &'static [u16],
+    name: Vec<u16>,
     i: i32,
-    found: bool,
+    found: Option<T>,
+    count: fn(&mut C, &T),
+    counts: Watcher<C>,
+    counts_version: Option<i32>,
+    interested: bool,
 }
 
-impl ToolCache {
-    fn new() -> Self {
-        ToolCache {
+impl<T: NamedValue + Copy, C: Copy + Default> NamedListCache<T, C> {
+    fn new(list: &'static NamedList, count: fn(&mut C, &T)) -> Self {
+        NamedListCache {
+            list,
             version: None,
-            tool: &[],
+            name: Vec::new(),
             i: -1,
-            found: false,
+            found: None,
+            count,
+            counts: Watcher::new(),
+            counts_version: None,
+            interested: false,
         }
     }
 
@@ -61,41 +105,73 @@ impl ToolCache {
         match e {
             None => {
                 self.version = None;
-                self.tool = &[]
+                self.name.clear()
             }
             Some(Env { pd, mem, .. }) => {
-                let new = get_tools_version(mem, pd);
+                let new = self.list.get_version(mem, pd);
                 if self.version != new {
                     self.version = new;
-                    self.tool = &[]
+                    self.name.clear()
                 }
             }
         }
     }
 
-    pub fn update_validity(&mut self, e: Option<&Env>) {
-        if !self.tool.is_empty() {
+    /// Checks whether the cached index is still valid,
+    /// and recounts the values when they change, if the counts were asked for since the last update
+    pub fn update(&mut self, e: Option<&Env>) {
+        if !self.name.is_empty() {
             self.update_version(e)
         }
+        if !self.interested {
+            // start over, so a gap doesn't look like a change
+            self.counts = Watcher::new();
+            self.counts_version = None;
+            return;
+        }
+        self.interested = false;
+        self.update_version(e);
+        let Some(e) = e else {
+            return;
+        };
+        if self.version.is_some() && self.counts_version != self.version {
+            let mut counts = C::default();
+            let count = self.count;
+            let scanned = self
+                .list
+                .for_each(e.mem, e.pd, |_, value: T| count(&mut counts, &value));
+            if scanned.is_some() {
+                self.counts.update_infallible(counts);
+                self.counts_version = self.version;
+                return;
+            }
+        }
+        if let Some(counts) = self.counts.pair.map(|p| p.current) {
+            self.counts.update_infallible(counts);
+        }
     }
 
-    pub fn has_tool(&mut self, tool_utf16: &'static [u16], e: &Env) -> bool {
+    pub fn get_counts_pair(&mut self) -> Option<Pair<C>> {
+        self.interested = true;
+        self.counts.pair
+    }
+
+    pub fn get(&mut self, name_utf16: &[u16], e: &Env) -> Option<T> {
         self.update_version(Some(e));
-        if self.version.is_none() {
-            return false;
-        }
-        if self.tool != tool_utf16 {
-            if let Some((i, is_unlocked)) = find_tool(tool_utf16, e.mem, e.pd) {
+        self.version?;
+        if self.name.as_slice() != name_utf16 {
+            if let Some((i, value)) = self.list.find(name_utf16, e.mem, e.pd) {
                 self.i = i;
-                self.found = is_unlocked;
+                self.found = Some(value);
             } else {
                 self.i = -1;
-                self.found = false;
+                self.found = None;
             }
-            self.tool = tool_utf16
+            self.name.clear();
+            self.name.extend_from_slice(name_utf16);
         } else if !self.i.is_negative() {
-            if let Some(is_unlocked) = read_tool(self.i, e.mem, e.pd) {
-                self.found = is_unlocked;
+            if let Some(value) = self.list.read(self.i, e.mem, e.pd) {
+                self.found = Some(value);
             }
         }
         self.found
@@ -109,7 +185,19 @@ pub struct Store {
     bools: BTreeMap<&'static str, StoreValue<bool>>,
     i32s: BTreeMap<&'static str, StoreValue<i32>>,
     strings: BTreeMap<&'static str, StoreValue<String>>,
-    tools: ToolCache,
+    tools: NamedListCache<ToolData, ()>,
+    quests: NamedListCache<QuestCompletion, QuestCounts>,
+    journal: NamedListCache<KillData, JournalCounts>,
+    collectables: NamedListCache<CollectableData, i32>,
+    relics: NamedListCache<RelicData, RelicCounts>,
+    mementos: NamedListCache<MementoData, i32>,
+    crests: NamedListCache<CrestData, i32>,
+    crest: CrestTracker,
+    area: AreaTracker,
+    completion: Completion,
+    /// Whether the completion has been updated since the last `update_all`
+    completion_fresh: bool,
+    spending: Spending,
 }
 
 impl Store {
@@ -121,7 +209,33 @@ impl Store {
             bools: BTreeMap::new(),
             i32s: BTreeMap::new(),
             strings: BTreeMap::new(),
-            tools: ToolCache::new(),
+            tools: NamedListCache::new(&TOOLS_LIST, |_, _| {}),
+            quests: NamedListCache::new(&QUESTS_LIST, |counts, quest| {
+                counts.accepted += quest.is_accepted as i32;
+                counts.completed += quest.is_done() as i32;
+            }),
+            journal: NamedListCache::new(&JOURNAL_LIST, |counts, kill_data| {
+                counts.seen += kill_data.has_been_seen as i32;
+                counts.encountered += (0 < kill_data.kills) as i32;
+            }),
+            collectables: NamedListCache::new(&COLLECTABLES_LIST, |count, collectable| {
+                *count += (0 < collectable.amount) as i32;
+            }),
+            relics: NamedListCache::new(&RELICS_LIST, |counts, relic| {
+                counts.collected += relic.is_collected as i32;
+                counts.deposited += relic.is_deposited as i32;
+            }),
+            mementos: NamedListCache::new(&MEMENTOS_LIST, |count, memento| {
+                *count += memento.is_deposited as i32;
+            }),
+            crests: NamedListCache::new(&CRESTS_LIST, |count, crest| {
+                *count += crest.is_unlocked as i32;
+            }),
+            crest: CrestTracker::new(),
+            area: AreaTracker::new(),
+            completion: Completion::new(),
+            completion_fresh: false,
+            spending: Spending::new(),
         }
     }
 
@@ -148,7 +262,106 @@ impl Store {
     }
 
     pub fn has_tool(&mut self, tool_utf16: &'static [u16], e: &Env) -> bool {
-        self.tools.has_tool(tool_utf16, e)
+        self.tools
+            .get(tool_utf16, e)
+            .is_some_and(|tool| tool.is_unlocked)
+    }
+
+    pub fn get_quest(&mut self, quest_utf16: &[u16], e: &Env) -> Option<QuestCompletion> {
+        self.quests.get(quest_utf16, e)
+    }
+
+    pub fn get_quest_counts_pair(&mut self) -> Option<Pair<QuestCounts>> {
+        self.quests.get_counts_pair()
+    }
+
+    pub fn get_kill_data(&mut self, enemy_utf16: &[u16], e: &Env) -> Option<KillData> {
+        self.journal.get(enemy_utf16, e)
+    }
+
+    pub fn get_journal_counts_pair(&mut self) -> Option<Pair<JournalCounts>> {
+        self.journal.get_counts_pair()
+    }
+
+    pub fn get_collectable(
+        &mut self,
+        collectable_utf16: &[u16],
+        e: &Env,
+    ) -> Option<CollectableData> {
+        self.collectables.get(collectable_utf16, e)
+    }
+
+    pub fn get_collectables_count_pair(&mut self) -> Option<Pair<i32>> {
+        self.collectables.get_counts_pair()
+    }
+
+    pub fn get_relic(&mut self, relic_utf16: &[u16], e: &Env) -> Option<RelicData> {
+        self.relics.get(relic_utf16, e)
+    }
+
+    pub fn get_relic_counts_pair(&mut self) -> Option<Pair<RelicCounts>> {
+        self.relics.get_counts_pair()
+    }
+
+    pub fn get_memento(&mut self, memento_utf16: &[u16], e: &Env) -> Option<MementoData> {
+        self.mementos.get(memento_utf16, e)
+    }
+
+    pub fn get_mementos_count_pair(&mut self) -> Option<Pair<i32>> {
+        self.mementos.get_counts_pair()
+    }
+
+    pub fn get_crest_data(&mut self, crest_utf16: &[u16], e: &Env) -> Option<CrestData> {
+        self.crests.get(crest_utf16, e)
+    }
+
+    pub fn get_crests_unlocked_pair(&mut self) -> Option<Pair<i32>> {
+        self.crests.get_counts_pair()
+    }
+
+    pub fn get_crest(&self) -> &CrestTracker {
+        &self.crest
+    }
+
+    pub fn clear_crest_swaps(&mut self) {
+        self.crest.clear_swaps()
+    }
+
+    pub fn get_area(&self) -> &AreaTracker {
+        &self.area
+    }
+
+    /// Updates the area once per tick, before splits look at it
+    pub fn update_area(&mut self, trans_now: bool, scene: &str, e: &Env) {
+        self.area.update(trans_now, scene, e)
+    }
+
+    pub fn clear_areas(&mut self) {
+        self.area.clear()
+    }
+
+    pub fn start_areas(&mut self) {
+        self.area.start_run()
+    }
+
+    pub fn get_completion(&mut self, e: &Env) -> &Completion {
+        if !self.completion_fresh {
+            self.completion.update(e);
+            self.completion_fresh = true;
+        }
+        &self.completion
+    }
+
+    pub fn get_spent(&self, scene: &str) -> i32 {
+        self.spending.get(scene)
+    }
+
+    pub fn add_spent(&mut self, scene: &str, amount: i32) {
+        self.spending.add(scene, amount)
+    }
+
+    pub fn clear_spent(&mut self) {
+        self.spending.clear()
     }
 
     pub fn get_bool_pair(&mut self, key: &str) -> Option<Pair<bool>> {
@@ -212,7 +425,15 @@ impl Store {
         self.timer_state.update(env);
         #[cfg(feature = "split-index")]
         self.split_index.update(env);
-        self.tools.update_validity(env);
+        self.tools.update(env);
+        self.quests.update(env);
+        self.journal.update(env);
+        self.collectables.update(env);
+        self.relics.update(env);
+        self.mementos.update(env);
+        self.crests.update(env);
+        self.crest.update(env);
+        self.completion_fresh = false;
         for v in self.bools.values_mut() {
             if v.update(env) {
                 v.interested = false;
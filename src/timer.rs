@@ -1,3 +1,5 @@
+use alloc::{format, string::String};
+use asr::time::Duration;
 use core::cmp::Ordering;
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -28,3 +30,44 @@ pub fn reached_up_to_split<T: PartialOrd>(
         _ => None,
     }
 }
+
+fn tenths(time: Duration) -> u64 {
+    (time.as_seconds_f64() * 10.0) as u64
+}
+
+/// Formats tenths of a second as real time, like `12:34.5`
+fn tenths_string(tenths: u64) -> String {
+    format!("{}:{:02}.{}", tenths / 600, (tenths / 10) % 60, tenths % 10)
+}
+
+/// Formats real time, like `12:34.5`
+pub fn duration_string(time: Duration) -> String {
+    tenths_string(tenths(time))
+}
+
+/// A custom variable showing real time,
+/// only set again when the tenths of a second it shows change
+pub struct TimeVariable {
+    name: &'static str,
+    shown: Option<u64>,
+}
+
+impl TimeVariable {
+    pub const fn new(name: &'static str) -> TimeVariable {
+        TimeVariable { name, shown: None }
+    }
+
+    pub fn set(&mut self, time: Duration) {
+        let tenths = tenths(time);
+        if self.shown == Some(tenths) {
+            return;
+        }
+        self.shown = Some(tenths);
+        asr::timer::set_variable(self.name, &tenths_string(tenths));
+    }
+
+    /// Forgets what it shows, so that the next `set` sets it again
+    pub fn clear(&mut self) {
+        self.shown = None;
+    }
+}
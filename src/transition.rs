@@ -0,0 +1,123 @@
+use asr::watcher::Pair;
+
+use alloc::string::String;
+
+use crate::silksong_memory::{
+    is_menu, Env, CINEMATIC_STAG_TRAVEL, DEATH_RESPAWN_MARKER_INIT,
+    SCENE_LOAD_VISUALIZATION_CONTINUE_FROM_SAVE,
+};
+
+/// How Hornet got from one scene to the next
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TransitionKind {
+    /// Through a gate, by walking, jumping, or falling
+    #[default]
+    Walk,
+    /// Riding the bellbeast between bellway stations
+    Bellway,
+    /// Riding a ventrica between ventrica stations
+    Ventrica,
+    /// Respawning at a bench after dying
+    DeathRespawn,
+    /// Respawning at a hazard respawn point after a hazard
+    HazardRespawn,
+    /// Loading a save file from the menu
+    SaveLoad,
+    /// Quitting to the menu
+    Menu,
+    /// Entering or leaving a memory
+    Memory,
+}
+
+impl TransitionKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            TransitionKind::Walk => "walk",
+            TransitionKind::Bellway => "bellway",
+            TransitionKind::Ventrica => "ventrica",
+            TransitionKind::DeathRespawn => "death respawn",
+            TransitionKind::HazardRespawn => "hazard respawn",
+            TransitionKind::SaveLoad => "save load",
+            TransitionKind::Menu => "menu",
+            TransitionKind::Memory => "memory",
+        }
+    }
+}
+
+/// GameManager.SceneLoadInfo, of the scene load in progress,
+/// where `TransitionID` counts up once per transition
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SceneLoadInfo {
+    pub entry_gate_name: String,
+    pub visualization: i32,
+    pub transition_id: i32,
+}
+
+impl SceneLoadInfo {
+    pub fn read(e: &Env) -> Option<SceneLoadInfo> {
+        let Env { mem, gm, .. } = e;
+        Some(SceneLoadInfo {
+            entry_gate_name: mem
+                .read_string(&gm.scene_load_entry_gate_name)
+                .unwrap_or_default(),
+            visualization: mem.deref(&gm.scene_load_visualization).ok()?,
+            transition_id: mem.deref(&gm.scene_load_transition_id).ok()?,
+        })
+    }
+}
+
+fn is_memory_scene(s: &str) -> bool {
+    s.starts_with("Memory_")
+}
+
+/// Ventrica stations are in rooms with `Tube` in their names,
+/// and riding one goes straight from one of those rooms to another
+fn is_ventrica_scene(s: &str) -> bool {
+    s.contains("Tube")
+}
+
+/// Classifies the transition between the scenes, as it happens,
+/// from the load info of the scene load when it was read,
+/// and otherwise from the entry gate, the player data, and the scene names
+pub fn classify_transition(
+    scenes: Pair<&str>,
+    load_info: Option<&SceneLoadInfo>,
+    e: &Env,
+) -> TransitionKind {
+    let Env { mem, pd, gm } = e;
+    if is_menu(scenes.current) {
+        return TransitionKind::Menu;
+    }
+    if is_menu(scenes.old)
+        || scenes.old == "Demo Start"
+        || load_info.is_some_and(|i| i.visualization == SCENE_LOAD_VISUALIZATION_CONTINUE_FROM_SAVE)
+    {
+        return TransitionKind::SaveLoad;
+    }
+    let entry_gate = match load_info {
+        Some(i) if !i.entry_gate_name.is_empty() => Some(i.entry_gate_name.clone()),
+        _ => mem.read_string(&gm.entry_gate_name),
+    };
+    if mem.deref(&pd.health).is_ok_and(|h: i32| h == 0)
+        || entry_gate.is_some_and(|g| g == DEATH_RESPAWN_MARKER_INIT)
+    {
+        return TransitionKind::DeathRespawn;
+    }
+    if mem.deref(&gm.hazard_respawning_hero).unwrap_or_default() {
+        return TransitionKind::HazardRespawn;
+    }
+    if is_ventrica_scene(scenes.old) && is_ventrica_scene(scenes.current) {
+        return TransitionKind::Ventrica;
+    }
+    // `travelling` can be set during ventrica rides too, so those come first
+    if scenes.old == CINEMATIC_STAG_TRAVEL
+        || scenes.current == CINEMATIC_STAG_TRAVEL
+        || mem.deref(&pd.travelling).unwrap_or_default()
+    {
+        return TransitionKind::Bellway;
+    }
+    if is_memory_scene(scenes.old) || is_memory_scene(scenes.current) {
+        return TransitionKind::Memory;
+    }
+    TransitionKind::Walk
+}